@@ -32,8 +32,9 @@ fn main() -> rschema::Result<()> {
       "type": "string"
     },
     "value": {
-      "type": "number",
+      "type": "integer",
       "minimum": 0,
+      "maximum": 2147483647,
       "exclusiveMaximum": 256
     }
   },
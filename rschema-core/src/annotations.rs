@@ -0,0 +1,40 @@
+use serde_json::Value;
+
+// The values given by the container attributes, which the derive macro hands over through `Schematic`.
+// Only Rschema reads them, since only it can give `Sealed`.
+#[derive(Debug, Default)]
+pub struct Annotations {
+    pub(crate) examples: Vec<Value>,
+    pub(crate) r#const: Option<Value>,
+    pub(crate) read_only: bool,
+    pub(crate) write_only: bool,
+}
+
+impl Annotations {
+    pub fn new(
+        examples: Vec<Value>,
+        r#const: Option<Value>,
+        read_only: bool,
+        write_only: bool,
+    ) -> Self {
+        Self {
+            examples,
+            r#const,
+            read_only,
+            write_only,
+        }
+    }
+
+    // The annotations which a wrapper, such as `Option<T>`, takes from `T`.
+    // `examples` and `const` are values of `T`, so they are not taken.
+    pub(crate) fn access(self) -> Self {
+        Self {
+            read_only: self.read_only,
+            write_only: self.write_only,
+            ..Default::default()
+        }
+    }
+}
+
+// Given only by Rschema to read the annotations of a type.
+pub struct Sealed(pub(crate) ());
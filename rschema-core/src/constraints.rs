@@ -0,0 +1,97 @@
+use crate::{
+    Number,
+    Type,
+};
+
+/// The keywords given by the field attributes, such as `#[rschema(min_length = 1)]`.
+/// 
+/// These are passed to [`Schematic::schema`](crate::Schematic::schema) of the type of the field.
+/// More keywords may be added, so create this with `Constraints::default()` and set the fields.
+/// 
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct Constraints {
+    /// `minLength` of a string.
+    /// 
+    pub min_length: Option<u64>,
+
+    /// `maxLength` of a string.
+    /// 
+    pub max_length: Option<u64>,
+
+    /// `pattern` of a string.
+    /// 
+    pub pattern: Option<String>,
+
+    /// `format` of a string.
+    /// 
+    pub format: Option<String>,
+
+    /// `minimum` of a number.
+    /// 
+    pub minimum: Option<Number>,
+
+    /// `maximum` of a number.
+    /// 
+    pub maximum: Option<Number>,
+
+    /// `multipleOf` of a number.
+    /// 
+    pub multiple_of: Option<Number>,
+
+    /// `exclusiveMinimum` of a number.
+    /// 
+    pub exclusive_minimum: Option<Number>,
+
+    /// `exclusiveMaximum` of a number.
+    /// 
+    pub exclusive_maximum: Option<Number>,
+
+    /// `minItems` of an array.
+    /// 
+    pub min_items: Option<usize>,
+
+    /// `maxItems` of an array.
+    /// 
+    pub max_items: Option<usize>,
+
+    /// `uniqueItems` of an array.
+    /// 
+    pub unique_items: Option<bool>,
+
+    /// `minProperties` of an object.
+    /// 
+    pub min_properties: Option<usize>,
+
+    /// `maxProperties` of an object.
+    /// 
+    pub max_properties: Option<usize>,
+
+    /// `propertyNames` of an object.
+    /// 
+    pub property_names: Option<Type>,
+
+    /// `contains` of an array.
+    /// 
+    pub contains: Option<Type>,
+
+    /// `minContains` of an array.
+    /// 
+    pub min_contains: Option<usize>,
+
+    /// `maxContains` of an array.
+    /// 
+    pub max_contains: Option<usize>,
+
+    /// `contentEncoding` of a string.
+    /// 
+    pub content_encoding: Option<String>,
+
+    /// `contentMediaType` of a string.
+    /// 
+    pub content_media_type: Option<String>,
+
+    /// `contentSchema` of a string.
+    /// 
+    pub content_schema: Option<Type>,
+}
@@ -1,68 +1,321 @@
 use indexmap::IndexMap;
 
 use std::{
-    any::TypeId,
-    ops::{
-        Deref,
-        DerefMut,
+    any::{
+        TypeId,
+        type_name,
     },
+    cell::RefCell,
 };
 
 use crate::{
     Definitions,
+    Error,
+    Naming,
+    Result,
     Schematic,
     Type,
+    type_id::type_id,
 };
 
-type DefsMapItem = (&'static str, Type);
+// The references to a definition use the Rust type name until the names in `$defs` are resolved.
+type DefsMapItem = (Origin, Type);
 type InnerMap = IndexMap<TypeId, DefsMapItem>;
 
+thread_local! {
+    // The types whose definitions are being collected by `extend_ty`.
+    static COLLECTING: RefCell<Vec<TypeId>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The definitions collected from the types, which become `$defs` of the schema.
+/// 
+/// Each type is defined once, even if it is used many times.
+/// 
 #[derive(Debug)]
 pub struct DefinitionsMap(InnerMap);
 
-impl Deref for DefinitionsMap {
-    type Target = InnerMap;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
+impl Default for DefinitionsMap {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl DerefMut for DefinitionsMap {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl DefinitionsMap {
+    /// Create an empty map.
+    /// 
+    pub fn new() -> Self {
+        Self(IndexMap::new())
     }
-}
 
-impl IntoIterator for DefinitionsMap {
-    type Item = (TypeId, DefsMapItem);
-    type IntoIter = indexmap::map::IntoIter<TypeId, DefsMapItem>;
+    /// Defines `T` with the given name.
+    /// 
+    /// The references to it are written as `Type::Ref(std::any::type_name::<T>().into())`.
+    /// 
+    pub fn insert<T: Schematic>(
+        &mut self,
+        name: impl Into<String>,
+        def: Type,
+    ) {
+        self.insert_origin::<T>(Some(name.into()), false, def);
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+    /// Defines `T` with the name which [`Naming`] of the schema generates.
+    /// 
+    pub fn insert_auto<T: Schematic>(
+        &mut self,
+        def: Type,
+    ) {
+        self.insert_origin::<T>(None, false, def);
     }
-}
 
-impl DefinitionsMap {
-    pub fn new() -> Self {
-        Self(IndexMap::new())
+    // Defines `T` which is written inline unless it reaches itself.
+    pub(crate) fn insert_recursive<T: Schematic>(
+        &mut self,
+        def: Type,
+    ) {
+        self.insert_origin::<T>(None, true, def);
     }
 
-    pub fn insert<T: 'static + Schematic>(
+    fn insert_origin<T: Schematic>(
         &mut self,
-        name: &'static str,
+        name: Option<String>,
+        recursive: bool,
         def: Type,
     ) {
-        let id = TypeId::of::<T>();
-        self.entry(id).or_insert((name, def));
+        let id = type_id::<T>();
+        self.0.entry(id).or_insert_with(|| {
+            let origin = Origin {
+                name,
+                ty_name: type_name::<T>(),
+                recursive,
+            };
+            (origin, def)
+        });
     }
 
+    /// Takes in the definitions which the type `T` uses.
+    /// 
+    /// A recursive type reaches itself while collecting its definitions.
+    /// Such a type is skipped, since the outer call already takes it in.
+    /// 
     pub fn extend_ty<T: Schematic>(&mut self) {
-        let definitions_map = <T as Schematic>::__defs_map();
-        self.extend(definitions_map);
+        let id = type_id::<T>();
+        if COLLECTING.with(|collecting| collecting.borrow().contains(&id)) {
+            return;
+        }
+
+        COLLECTING.with(|collecting| collecting.borrow_mut().push(id));
+        let definitions_map = <T as Schematic>::definitions();
+        COLLECTING.with(|collecting| collecting.borrow_mut().pop());
+
+        self.append(definitions_map);
+    }
+
+    /// Takes in the definitions of another map.
+    /// 
+    pub fn append(&mut self, other: DefinitionsMap) {
+        self.0.extend(other.0);
+    }
+
+    /// The number of the definitions.
+    /// 
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether no definitions are collected.
+    /// 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The definitions and where they come from, in the order they are collected.
+    /// 
+    pub fn iter(&self) -> impl Iterator<Item = (&Origin, &Type)> {
+        self.0.values().map(|(origin, def)| (origin, def))
+    }
+
+    /// Builds `$defs`.
+    /// 
+    /// The references among the definitions are rewritten into their names in `$defs`,
+    /// but the ones in other types are not.
+    /// Give this map to [`Schema::from_type`](crate::Schema::from_type) to rewrite the ones in the root type as well.
+    /// 
+    /// # Errors
+    /// 
+    /// This fails with [`Error::DefinitionsCollisionError`] if different types are defined with the same name.
+    /// 
+    pub fn build(self) -> Result<Definitions> {
+        let (defs, _, collided) = self.resolve(&mut Type::Null, &Naming::default());
+        match collided {
+            Some(collided) => Err(collided.to_error()),
+            None => Ok(defs),
+        }
     }
 
-    pub fn build(self) -> Definitions {
-        Definitions::from_iter(self.0.into_values())
+    // Drops the definitions of recursive types which nothing refers to from `root`.
+    // 
+    // Such a type is reached inside another one, such as `B` in `A` when `A` and `B` refer to each other
+    // and `A` is the root, since `B` is written inline there.
+    pub(crate) fn prune(
+        &mut self,
+        root: &mut Type,
+    ) {
+        fn add_refs(ty: &mut Type, reached: &mut Vec<String>) {
+            ty.refs_mut(&mut |r#ref| {
+                if !reached.contains(r#ref) {
+                    reached.push(r#ref.clone());
+                }
+            });
+        }
+
+        let mut reached = vec![];
+        add_refs(root, &mut reached);
+        for (origin, def) in self.0.values_mut() {
+            if !origin.recursive {
+                add_refs(def, &mut reached);
+            }
+        }
+
+        // The definitions reached by the others are followed until no more are found.
+        let mut visited = vec![];
+        while let Some((id, (_, def))) = self.0
+            .iter_mut()
+            .find(|(id, (origin, _))| {
+                origin.recursive
+                    && !visited.contains(*id)
+                    && reached.iter().any(|r#ref| r#ref == origin.ty_name)
+            })
+        {
+            visited.push(*id);
+            add_refs(def, &mut reached);
+        }
+
+        self.0.retain(|id, (origin, _)| !origin.recursive || visited.contains(id));
+    }
+
+    // Gives the definitions their names in `$defs`, and rewrites the references in `root` and the definitions into them.
+    pub(crate) fn resolve(
+        self,
+        root: &mut Type,
+        naming: &Naming,
+    ) -> (Definitions, Vec<Origin>, Option<Collided>) {
+        let (origins, defs): (Vec<_>, Vec<_>) = self.0.into_values().unzip();
+        let (names, collided) = name_defs(&origins, naming);
+        let keys: Vec<_> = origins
+            .iter()
+            .map(|origin| origin.ty_name)
+            .collect();
+        let defs = rename_defs(root, defs, &keys, names);
+
+        (defs, origins, collided)
+    }
+}
+
+/// Where a definition in [`DefinitionsMap`] comes from.
+/// 
+#[derive(Debug)]
+pub struct Origin {
+    // The name given by `#[rschema(defs = "name")]`, or `None` if it is auto-generated.
+    name: Option<String>,
+
+    ty_name: &'static str,
+
+    // Whether it is defined only because the type reaches itself.
+    recursive: bool,
+}
+
+impl Origin {
+    /// The name given to the definition, or `None` if it is named by [`Naming`].
+    /// 
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The Rust type name of the defined type, which the references use until `$defs` is built.
+    /// 
+    pub fn ty_name(&self) -> &'static str {
+        self.ty_name
+    }
+}
+
+// Names the definitions in order.
+// A name already taken by another type is numbered, such as `Config_2`, and the first of such collisions is returned.
+pub(crate) fn name_defs(
+    origins: &[Origin],
+    naming: &Naming,
+) -> (Vec<String>, Option<Collided>) {
+    let mut owners = IndexMap::<String, &'static str>::new();
+    let mut collided = None;
+
+    for Origin { name, ty_name, .. } in origins {
+        let name = match name {
+            Some(name) => name.clone(),
+            None => naming.name(ty_name),
+        };
+
+        let mut unique = name.clone();
+        let mut n = 1;
+        while let Some(owner) = owners.get(&unique) {
+            if collided.is_none() {
+                collided = Some(Collided {
+                    name: name.clone(),
+                    first: owner,
+                    second: ty_name,
+                });
+            }
+            n += 1;
+            unique = format!("{}_{}", name, n);
+        }
+        owners.insert(unique, ty_name);
+    }
+
+    (owners.into_keys().collect(), collided)
+}
+
+// Rewrites the references to `keys` in `root` and `defs` into `names`, and defines `defs` with `names`.
+pub(crate) fn rename_defs(
+    root: &mut Type,
+    defs: impl IntoIterator<Item = Type>,
+    keys: &[&str],
+    names: Vec<String>,
+) -> Definitions {
+    let renames: IndexMap<_, _> = keys
+        .iter()
+        .copied()
+        .zip(names.iter().cloned())
+        .collect();
+    let mut rename = |r#ref: &mut String| {
+        if let Some(name) = renames.get(r#ref.as_str()) {
+            r#ref.clone_from(name);
+        }
+    };
+
+    root.refs_mut(&mut rename);
+    names
+        .into_iter()
+        .zip(defs)
+        .map(|(name, mut def)| {
+            def.refs_mut(&mut rename);
+            (name, def)
+        })
+        .collect()
+}
+
+// Two different types defined with the same name in `$defs`.
+#[derive(Debug)]
+pub(crate) struct Collided {
+    name: String,
+    first: &'static str,
+    second: &'static str,
+}
+
+impl Collided {
+    pub(crate) fn to_error(&self) -> Error {
+        Error::DefinitionsCollisionError {
+            name: self.name.clone(),
+            first: self.first.into(),
+            second: self.second.into(),
+        }
     }
 }
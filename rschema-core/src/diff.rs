@@ -0,0 +1,943 @@
+use serde::Serialize;
+use serde_json::{
+    Number,
+    Value,
+};
+
+use std::{
+    cmp::Ordering,
+    fmt,
+};
+
+use crate::{
+    AdditionalProperties,
+    ArrayKeys,
+    Definitions,
+    ExclusiveGroup,
+    Items,
+    NumericKeys,
+    ObjectKeys,
+    Property,
+    Schema,
+    StringKeys,
+    Type,
+    compare,
+    validation::{
+        is_multiple_of,
+        push,
+    },
+};
+
+/// Compare two versions of a schema.
+/// 
+/// Every change between `old` and `new` is classified by which values each version accepts.
+/// References are followed into the definitions of each schema.
+/// 
+/// ```
+/// use rschema_core::{
+///     Compatibility,
+///     Schema,
+/// };
+/// 
+/// # fn main() -> rschema_core::Result<()> {
+/// let old: Schema = r#"{"title":"Config","type":"object","properties":{"value":{"type":"integer","maximum":10}}}"#.parse()?;
+/// let new: Schema = r#"{"title":"Config","type":"object","properties":{"value":{"type":"integer","maximum":5}}}"#.parse()?;
+/// 
+/// let diff = rschema_core::diff(&old, &new);
+/// 
+/// assert_eq!(diff.compatibility, Compatibility::Forward);
+/// assert_eq!(
+///     diff.to_string(),
+///     "[forward] /properties/value/maximum: `maximum` changed from 10 to 5\n",
+/// );
+/// # Ok(())
+/// # }
+/// ```
+/// 
+pub fn diff(
+    old: &Schema,
+    new: &Schema,
+) -> Diff {
+    let mut differ = Differ {
+        old_defs: old.defs(),
+        new_defs: new.defs(),
+        refs: Vec::new(),
+        changes: Vec::new(),
+    };
+
+    differ.annotation("", "title", Some(&old.title), Some(&new.title));
+    differ.annotation("", "description", old.description.as_ref(), new.description.as_ref());
+    differ.annotation("", "examples", Some(&old.examples), Some(&new.examples));
+    differ.r#const("", old.r#const.as_ref(), new.r#const.as_ref());
+    differ.ty(old.ty(), new.ty(), "");
+
+    Diff::new(differ.changes)
+}
+
+/// Compare two versions of a type, such as the ones built at runtime by [`Type::object`].
+/// 
+/// References are followed into `old_defs` and `new_defs` respectively.
+/// 
+/// ```
+/// use rschema_core::{
+///     Compatibility,
+///     Definitions,
+///     Type,
+/// };
+/// 
+/// let old = Type::string().build();
+/// let new = Type::string().max_length(10).build();
+/// 
+/// let diff = rschema_core::diff_types(&old, &new, &Definitions::new(), &Definitions::new());
+/// 
+/// assert_eq!(diff.compatibility, Compatibility::Forward);
+/// ```
+/// 
+pub fn diff_types(
+    old: &Type,
+    new: &Type,
+    old_defs: &Definitions,
+    new_defs: &Definitions,
+) -> Diff {
+    let mut differ = Differ {
+        old_defs,
+        new_defs,
+        refs: Vec::new(),
+        changes: Vec::new(),
+    };
+
+    differ.ty(old, new, "");
+
+    Diff::new(differ.changes)
+}
+
+/// Which versions of a schema can read the values of the other.
+/// 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compatibility {
+    /// Both versions accept the same values, such as when only a description changes.
+    Full,
+
+    /// The new version accepts every value which the old version accepts.
+    Backward,
+
+    /// The old version accepts every value which the new version accepts.
+    Forward,
+
+    /// Neither version accepts every value of the other.
+    Breaking,
+}
+
+impl Compatibility {
+    fn new(
+        backward: bool,
+        forward: bool,
+    ) -> Self {
+        match (backward, forward) {
+            (true, true) => Self::Full,
+            (true, false) => Self::Backward,
+            (false, true) => Self::Forward,
+            (false, false) => Self::Breaking,
+        }
+    }
+
+    fn reversed(self) -> Self {
+        Self::new(self.is_forward(), self.is_backward())
+    }
+
+    /// The compatibility which holds for both changes.
+    /// 
+    pub fn and(
+        self,
+        other: Self,
+    ) -> Self {
+        Self::new(
+            self.is_backward() && other.is_backward(),
+            self.is_forward() && other.is_forward(),
+        )
+    }
+
+    /// Whether the new version accepts every value which the old version accepts.
+    /// 
+    pub fn is_backward(self) -> bool {
+        matches!(self, Self::Full | Self::Backward)
+    }
+
+    /// Whether the old version accepts every value which the new version accepts.
+    /// 
+    pub fn is_forward(self) -> bool {
+        matches!(self, Self::Full | Self::Forward)
+    }
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::Full => "full",
+            Self::Backward => "backward",
+            Self::Forward => "forward",
+            Self::Breaking => "breaking",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A change of a keyword between two versions of a schema.
+/// 
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Change {
+    /// JSON pointer to the changed keyword in the new schema.
+    pub path: String,
+
+    /// Which versions can read the values of the other as far as this change is concerned.
+    pub compatibility: Compatibility,
+
+    /// A human-readable description of the change.
+    pub message: String,
+
+    /// The value in the old schema, if it had one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+
+    /// The value in the new schema, if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}: {}", self.compatibility, self.path, self.message)
+    }
+}
+
+/// The result of [`diff`](fn@diff) and [`diff_types`](fn@diff_types).
+/// 
+/// This is serialized as a machine-readable report, and displayed as a changelog with one change per line.
+/// 
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Diff {
+    /// The compatibility which holds for all of the changes.
+    pub compatibility: Compatibility,
+
+    /// The changes in the order of the keywords in the schemas.
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    fn new(changes: Vec<Change>) -> Self {
+        let compatibility = changes
+            .iter()
+            .fold(Compatibility::Full, |acc, change| acc.and(change.compatibility));
+
+        Diff {
+            compatibility,
+            changes,
+        }
+    }
+
+    /// Whether some values cannot be read by either version.
+    /// 
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility == Compatibility::Breaking
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+struct Differ<'a> {
+    old_defs: &'a Definitions,
+    new_defs: &'a Definitions,
+    refs: Vec<(&'a str, &'a str)>,
+    changes: Vec<Change>,
+}
+
+impl<'a> Differ<'a> {
+    fn ty(
+        &mut self,
+        old: &'a Type,
+        new: &'a Type,
+        path: &str,
+    ) {
+        match (old, new) {
+            (Type::Ref(old_name), Type::Ref(new_name)) => {
+                // A pair of definitions which is already being compared is not compared again.
+                let entry = (old_name.as_str(), new_name.as_str());
+                if self.refs.contains(&entry) {
+                    return;
+                }
+
+                if let (Some(old), Some(new)) = (self.old_defs.get(old_name), self.new_defs.get(new_name)) {
+                    self.refs.push(entry);
+                    self.ty(old, new, &push("/$defs", new_name));
+                    self.refs.pop();
+                }
+            },
+            (Type::Ref(name), _) => {
+                if let Some(old) = self.old_defs.get(name) {
+                    self.ty(old, new, path);
+                }
+            },
+            (_, Type::Ref(name)) => {
+                if let Some(new) = self.new_defs.get(name) {
+                    self.ty(old, new, &push("/$defs", name));
+                }
+            },
+            // The keywords which Rschema does not handle are not compared.
+            (Type::Extra(old), _) => self.ty(&old.ty, new, path),
+            (_, Type::Extra(new)) => self.ty(old, &new.ty, path),
+            (Type::String(old), Type::String(new)) => self.string(old, new, path),
+            (Type::Integer(old), Type::Integer(new)) |
+            (Type::Number(old), Type::Number(new)) => self.numeric(old, new, path),
+            (Type::Integer(old_keys), Type::Number(new_keys)) => {
+                self.keyword(path, "type", Compatibility::Backward, Some(type_name(old)), Some(type_name(new)));
+                self.numeric(old_keys, new_keys, path);
+            },
+            (Type::Number(old_keys), Type::Integer(new_keys)) => {
+                self.keyword(path, "type", Compatibility::Forward, Some(type_name(old)), Some(type_name(new)));
+                self.numeric(old_keys, new_keys, path);
+            },
+            (Type::Boolean, Type::Boolean) |
+            (Type::Null, Type::Null) => {},
+            (Type::Array(old), Type::Array(new)) => self.array(old, new, path),
+            (Type::Object(old), Type::Object(new)) => self.object(old, new, path),
+            (Type::Enum(old), Type::Enum(new)) => {
+                self.branches(&old.any_of, &new.any_of, path, Some("anyOf"), Some("anyOf"), Compatibility::Backward);
+            },
+            (Type::AllOf(old), Type::AllOf(new)) => {
+                self.branches(&old.all_of, &new.all_of, path, Some("allOf"), Some("allOf"), Compatibility::Forward);
+            },
+            (Type::OneOf(old), Type::OneOf(new)) => {
+                self.branches(&old.one_of, &new.one_of, path, Some("oneOf"), Some("oneOf"), Compatibility::Backward);
+            },
+            (Type::Not(old), Type::Not(new)) => {
+                // Accepting more values in `not` means accepting less values.
+                let len = self.changes.len();
+                self.ty(&old.not, &new.not, &push(path, "not"));
+                for change in &mut self.changes[len..] {
+                    change.compatibility = change.compatibility.reversed();
+                }
+            },
+            (Type::Tuple(old), Type::Tuple(new)) => {
+                let items_path = push(path, "items");
+                self.list(&old.items, &new.items, &items_path, Compatibility::Backward, |differ, old, new, path| {
+                    differ.ty(old, new, path);
+                });
+
+                // The length of a tuple is fixed by these keywords.
+                self.lower_bound(path, "minItems", Some(old.items.len().into()), Some(new.items.len().into()));
+                self.upper_bound(path, "maxItems", Some(old.items.len().into()), Some(new.items.len().into()));
+            },
+            // A plain type is compared as a single branch, such as when `String` becomes `Option<String>`.
+            (_, Type::Enum(_) | Type::OneOf(_)) if !matches!(old, Type::Enum(_) | Type::OneOf(_)) => {
+                let (keyword, new) = match new {
+                    Type::Enum(new) => ("anyOf", &new.any_of),
+                    Type::OneOf(new) => ("oneOf", &new.one_of),
+                    _ => unreachable!(),
+                };
+                self.branches(std::slice::from_ref(old), new, path, None, Some(keyword), Compatibility::Backward);
+            },
+            (Type::Enum(_) | Type::OneOf(_), _) if !matches!(new, Type::Enum(_) | Type::OneOf(_)) => {
+                let (keyword, old) = match old {
+                    Type::Enum(old) => ("anyOf", &old.any_of),
+                    Type::OneOf(old) => ("oneOf", &old.one_of),
+                    _ => unreachable!(),
+                };
+                self.branches(old, std::slice::from_ref(new), path, Some(keyword), None, Compatibility::Backward);
+            },
+            _ => {
+                self.keyword(path, "type", Compatibility::Breaking, Some(type_name(old)), Some(type_name(new)));
+            },
+        }
+    }
+
+    // Compares the branches of `anyOf`, `oneOf` or `allOf`, whose order does not matter.
+    // Each old branch is paired with the closest new one, and the others are added or removed.
+    // The keyword is `None` for a plain type, which is compared as a single branch.
+    fn branches(
+        &mut self,
+        old: &'a [Type],
+        new: &'a [Type],
+        path: &str,
+        old_keyword: Option<&str>,
+        new_keyword: Option<&str>,
+        added: Compatibility,
+    ) {
+        let branch_path = |keyword: Option<&str>, i: usize| match keyword {
+            Some(keyword) => push(&push(path, keyword), i),
+            None => path.to_string(),
+        };
+
+        let mut pairs = vec![];
+        let mut unpaired: Vec<_> = (0..new.len()).collect();
+        for (i, old_branch) in old.iter().enumerate() {
+            let closest = unpaired
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, &j)| self.distance(old_branch, &new[j]));
+            if let Some((k, &j)) = closest {
+                unpaired.remove(k);
+                pairs.push((i, j));
+            }
+        }
+        pairs.sort_by_key(|&(_, j)| j);
+
+        for &(i, j) in &pairs {
+            self.ty(&old[i], &new[j], &branch_path(new_keyword, j));
+        }
+        for j in unpaired {
+            self.change(&branch_path(new_keyword, j), added, format!("branch {} added", j), None, None);
+        }
+        for i in (0..old.len()).filter(|i| pairs.iter().all(|(paired, _)| paired != i)) {
+            self.change(&branch_path(old_keyword, i), added.reversed(), format!("branch {} removed", i), None, None);
+        }
+    }
+
+    // How far two types are, by the number of breaking changes and then of all changes between them.
+    fn distance(
+        &mut self,
+        old: &'a Type,
+        new: &'a Type,
+    ) -> (usize, usize) {
+        let len = self.changes.len();
+        self.ty(old, new, "");
+        let changes = self.changes.split_off(len);
+        let breaking = changes
+            .iter()
+            .filter(|change| change.compatibility == Compatibility::Breaking)
+            .count();
+
+        (breaking, changes.len())
+    }
+
+    fn string(
+        &mut self,
+        old: &StringKeys,
+        new: &StringKeys,
+        path: &str,
+    ) {
+        self.lower_bound(path, "minLength", old.min_length.map(Number::from), new.min_length.map(Number::from));
+        self.upper_bound(path, "maxLength", old.max_length.map(Number::from), new.max_length.map(Number::from));
+        self.constraint(path, "pattern", old.pattern.as_ref(), new.pattern.as_ref());
+        self.constraint(path, "format", old.format.as_ref(), new.format.as_ref());
+
+        // The content keywords are annotations, which do not restrict the values.
+        self.annotation(path, "contentEncoding", old.content_encoding.as_ref(), new.content_encoding.as_ref());
+        self.annotation(path, "contentMediaType", old.content_media_type.as_ref(), new.content_media_type.as_ref());
+        let old_content_schema = old.content_schema.as_ref().and_then(|ty| serde_json::to_value(ty).ok());
+        let new_content_schema = new.content_schema.as_ref().and_then(|ty| serde_json::to_value(ty).ok());
+        self.annotation(path, "contentSchema", old_content_schema.as_ref(), new_content_schema.as_ref());
+
+        let enum_path = push(path, "enum");
+        match (old.enm.is_empty(), new.enm.is_empty()) {
+            (true, true) => {},
+            (true, false) => {
+                self.keyword(path, "enum", Compatibility::Forward, None::<&Vec<String>>, Some(&new.enm));
+            },
+            (false, true) => {
+                self.keyword(path, "enum", Compatibility::Backward, Some(&old.enm), None::<&Vec<String>>);
+            },
+            (false, false) => {
+                for value in old.enm.iter().filter(|value| !new.enm.contains(value)) {
+                    let message = format!("enum value {:?} removed", value);
+                    self.change(&enum_path, Compatibility::Forward, message, Some(value.as_str().into()), None);
+                }
+                for value in new.enm.iter().filter(|value| !old.enm.contains(value)) {
+                    let message = format!("enum value {:?} added", value);
+                    self.change(&enum_path, Compatibility::Backward, message, None, Some(value.as_str().into()));
+                }
+            },
+        }
+    }
+
+    fn numeric(
+        &mut self,
+        old: &NumericKeys,
+        new: &NumericKeys,
+        path: &str,
+    ) {
+        self.lower_bound(path, "minimum", old.minimum.clone(), new.minimum.clone());
+        self.upper_bound(path, "maximum", old.maximum.clone(), new.maximum.clone());
+        self.lower_bound(path, "exclusiveMinimum", old.exclusive_minimum.clone(), new.exclusive_minimum.clone());
+        self.upper_bound(path, "exclusiveMaximum", old.exclusive_maximum.clone(), new.exclusive_maximum.clone());
+
+        let compatibility = match (&old.multiple_of, &new.multiple_of) {
+            (None, None) => return,
+            (Some(old), Some(new)) if old == new => return,
+            (Some(old), Some(new)) => Compatibility::new(is_multiple_of(old, new), is_multiple_of(new, old)),
+            (None, Some(_)) => Compatibility::Forward,
+            (Some(_), None) => Compatibility::Backward,
+        };
+        self.keyword(path, "multipleOf", compatibility, old.multiple_of.as_ref(), new.multiple_of.as_ref());
+    }
+
+    fn array(
+        &mut self,
+        old: &'a ArrayKeys,
+        new: &'a ArrayKeys,
+        path: &str,
+    ) {
+        let items_path = push(path, "items");
+        match (&*old.items, &*new.items) {
+            (Items::Single(old), Items::Single(new)) => self.ty(old, new, &items_path),
+            (Items::Tuple(old), Items::Tuple(new)) => {
+                self.list(old, new, &items_path, Compatibility::Backward, |differ, old, new, path| {
+                    differ.property(old, new, path);
+                });
+            },
+            (Items::Single(_), Items::Tuple(_)) => {
+                self.change(&items_path, Compatibility::Breaking, "`items` changed to ordered items", None, None);
+            },
+            (Items::Tuple(_), Items::Single(_)) => {
+                self.change(&items_path, Compatibility::Breaking, "`items` changed to unordered items", None, None);
+            },
+        }
+
+        self.lower_bound(path, "minItems", old.min_items.map(Number::from), new.min_items.map(Number::from));
+        self.upper_bound(path, "maxItems", old.max_items.map(Number::from), new.max_items.map(Number::from));
+
+        let old_unique = old.unique_items == Some(true);
+        let new_unique = new.unique_items == Some(true);
+        if old_unique != new_unique {
+            let compatibility = Compatibility::new(old_unique, new_unique);
+            self.keyword(path, "uniqueItems", compatibility, Some(old_unique), Some(new_unique));
+        }
+
+        match (&old.contains, &new.contains) {
+            (None, None) => {},
+            (Some(old_contains), Some(new_contains)) => {
+                self.ty(old_contains, new_contains, &push(path, "contains"));
+
+                // `minContains` is 1 if not given.
+                let old_min = old.min_contains.unwrap_or(1);
+                let new_min = new.min_contains.unwrap_or(1);
+                self.lower_bound(path, "minContains", Some(old_min.into()), Some(new_min.into()));
+                self.upper_bound(path, "maxContains", old.max_contains.map(Number::from), new.max_contains.map(Number::from));
+            },
+            (None, Some(new_contains)) => {
+                self.keyword(path, "contains", Compatibility::Forward, None::<&Type>, Some(new_contains));
+            },
+            (Some(old_contains), None) => {
+                self.keyword(path, "contains", Compatibility::Backward, Some(old_contains), None::<&Type>);
+            },
+        }
+    }
+
+    fn object(
+        &mut self,
+        old: &'a ObjectKeys,
+        new: &'a ObjectKeys,
+        path: &str,
+    ) {
+        let properties_path = push(path, "properties");
+
+        for (name, old_property) in old.properties.iter() {
+            let property_path = push(&properties_path, name);
+            match new.properties.get(name) {
+                Some(new_property) => self.property(old_property, new_property, &property_path),
+                None => {
+                    // The property falls under `additionalProperties` of the new version.
+                    let required = old.required.contains(name);
+                    let allowed = matches!(*new.additional_properties, AdditionalProperties::Boolean(true));
+                    let denied = matches!(*new.additional_properties, AdditionalProperties::Boolean(false));
+                    let compatibility = Compatibility::new(allowed, denied && !required);
+                    let message = format!("property `{}` removed", name);
+                    self.change(&property_path, compatibility, message, None, None);
+                },
+            }
+        }
+
+        for name in new.properties.keys() {
+            if old.properties.contains_key(name) {
+                continue;
+            }
+
+            // The property fell under `additionalProperties` of the old version.
+            let required = new.required.contains(name);
+            let allowed = matches!(*old.additional_properties, AdditionalProperties::Boolean(true));
+            let denied = matches!(*old.additional_properties, AdditionalProperties::Boolean(false));
+            let compatibility = Compatibility::new(denied && !required, allowed);
+            let message = if required {
+                format!("required property `{}` added", name)
+            } else {
+                format!("property `{}` added", name)
+            };
+            self.change(&push(&properties_path, name), compatibility, message, None, None);
+        }
+
+        // The requirement of added or removed properties is already considered above.
+        let is_common = |name: &String| {
+            old.properties.contains_key(name) == new.properties.contains_key(name)
+        };
+        let required_path = push(path, "required");
+        for name in old.required.iter().filter(|name| !new.required.contains(name) && is_common(name)) {
+            let message = format!("property `{}` is no longer required", name);
+            self.change(&required_path, Compatibility::Backward, message, None, None);
+        }
+        for name in new.required.iter().filter(|name| !old.required.contains(name) && is_common(name)) {
+            let message = format!("property `{}` became required", name);
+            self.change(&required_path, Compatibility::Forward, message, None, None);
+        }
+
+        // Properties matching a pattern fell under `additionalProperties` without it, as the properties above.
+        let pattern_properties_path = push(path, "patternProperties");
+        for (pattern, old_ty) in old.pattern_properties.iter() {
+            let pattern_path = push(&pattern_properties_path, pattern);
+            match new.pattern_properties.get(pattern) {
+                Some(new_ty) => self.ty(old_ty, new_ty, &pattern_path),
+                None => {
+                    let allowed = matches!(*new.additional_properties, AdditionalProperties::Boolean(true));
+                    let denied = matches!(*new.additional_properties, AdditionalProperties::Boolean(false));
+                    let message = format!("pattern `{}` removed", pattern);
+                    self.change(&pattern_path, Compatibility::new(allowed, denied), message, None, None);
+                },
+            }
+        }
+        for pattern in new.pattern_properties.keys().filter(|pattern| !old.pattern_properties.contains_key(*pattern)) {
+            let allowed = matches!(*old.additional_properties, AdditionalProperties::Boolean(true));
+            let denied = matches!(*old.additional_properties, AdditionalProperties::Boolean(false));
+            let message = format!("pattern `{}` added", pattern);
+            self.change(&push(&pattern_properties_path, pattern), Compatibility::new(denied, allowed), message, None, None);
+        }
+
+        match (&old.property_names, &new.property_names) {
+            (None, None) => {},
+            (Some(old), Some(new)) => self.ty(old, new, &push(path, "propertyNames")),
+            (None, Some(new)) => {
+                self.keyword(path, "propertyNames", Compatibility::Forward, None::<&Type>, Some(new));
+            },
+            (Some(old), None) => {
+                self.keyword(path, "propertyNames", Compatibility::Backward, Some(old), None::<&Type>);
+            },
+        }
+
+        self.lower_bound(path, "minProperties", old.min_properties.map(Number::from), new.min_properties.map(Number::from));
+        self.upper_bound(path, "maxProperties", old.max_properties.map(Number::from), new.max_properties.map(Number::from));
+
+        let additional_path = push(path, "additionalProperties");
+        match (&*old.additional_properties, &*new.additional_properties) {
+            (AdditionalProperties::Complex(old), AdditionalProperties::Complex(new)) => {
+                self.ty(old, new, &additional_path);
+            },
+            (AdditionalProperties::Boolean(old_allowed), AdditionalProperties::Boolean(new_allowed)) if old_allowed == new_allowed => {},
+            (old_keyword, new_keyword) => {
+                let compatibility = match (old_keyword, new_keyword) {
+                    (AdditionalProperties::Boolean(true), _) |
+                    (_, AdditionalProperties::Boolean(false)) => Compatibility::Forward,
+                    _ => Compatibility::Backward,
+                };
+                self.keyword(path, "additionalProperties", compatibility, Some(old_keyword), Some(new_keyword));
+            },
+        }
+
+        let dependent_required_path = push(path, "dependentRequired");
+        for (name, old_required) in old.dependent_required.iter() {
+            let path = push(&dependent_required_path, name);
+            let new_required = new.dependent_required.get(name).map(Vec::as_slice).unwrap_or_default();
+            for required in old_required.iter().filter(|required| !new_required.contains(required)) {
+                let message = format!("property `{}` no longer requires `{}`", name, required);
+                self.change(&path, Compatibility::Backward, message, None, None);
+            }
+        }
+        for (name, new_required) in new.dependent_required.iter() {
+            let path = push(&dependent_required_path, name);
+            let old_required = old.dependent_required.get(name).map(Vec::as_slice).unwrap_or_default();
+            for required in new_required.iter().filter(|required| !old_required.contains(required)) {
+                let message = format!("property `{}` now requires `{}`", name, required);
+                self.change(&path, Compatibility::Forward, message, None, None);
+            }
+        }
+
+        let dependent_schemas_path = push(path, "dependentSchemas");
+        for (name, old_ty) in old.dependent_schemas.iter() {
+            let path = push(&dependent_schemas_path, name);
+            match new.dependent_schemas.get(name) {
+                Some(new_ty) => self.ty(old_ty, new_ty, &path),
+                None => {
+                    let message = format!("dependent schema of `{}` removed", name);
+                    self.change(&path, Compatibility::Backward, message, None, None);
+                },
+            }
+        }
+        for name in new.dependent_schemas.keys().filter(|name| !old.dependent_schemas.contains_key(*name)) {
+            let message = format!("dependent schema of `{}` added", name);
+            self.change(&push(&dependent_schemas_path, name), Compatibility::Forward, message, None, None);
+        }
+
+        // A single condition is written in the object itself, and the others in `allOf`.
+        let len = old.conditionals.len().max(new.conditionals.len());
+        let all_of_path = push(path, "allOf");
+        for i in 0..len {
+            let conditional_path = if len == 1 {
+                push(path, "if")
+            } else {
+                push(&all_of_path, i)
+            };
+            let old = old.conditionals.get(i).and_then(|old| serde_json::to_value(old).ok());
+            let new = new.conditionals.get(i).and_then(|new| serde_json::to_value(new).ok());
+            let (compatibility, message) = match (&old, &new) {
+                (Some(old), Some(new)) if old == new => continue,
+                (Some(_), Some(_)) => (Compatibility::Breaking, "condition changed"),
+                (None, _) => (Compatibility::Forward, "condition added"),
+                (_, None) => (Compatibility::Backward, "condition removed"),
+            };
+            self.change(&conditional_path, compatibility, message, old, new);
+        }
+
+        self.exclusive_groups(&old.exclusive_groups, &new.exclusive_groups, path);
+    }
+
+    // Groups are matched by their properties, since the positions in `allOf` depend on the conditions.
+    fn exclusive_groups(
+        &mut self,
+        old: &[ExclusiveGroup],
+        new: &[ExclusiveGroup],
+        path: &str,
+    ) {
+        fn find<'a>(groups: &'a [ExclusiveGroup], group: &ExclusiveGroup) -> Option<&'a ExclusiveGroup> {
+            groups
+                .iter()
+                .find(|other| other.properties.iter().any(|name| group.properties.contains(name)))
+        }
+
+        for old_group in old {
+            let value = serde_json::to_value(old_group).ok();
+            let new_group = find(new, old_group);
+            let (compatibility, message) = match new_group {
+                Some(new_group) if new_group == old_group => continue,
+                Some(new_group) if new_group.properties == old_group.properties => {
+                    // Only `required` changed.
+                    let compatibility = if new_group.required {
+                        Compatibility::Forward
+                    } else {
+                        Compatibility::Backward
+                    };
+                    (compatibility, "exclusive group changed")
+                },
+                Some(_) => (Compatibility::Breaking, "exclusive group changed"),
+                None => (Compatibility::Backward, "exclusive group removed"),
+            };
+            let new_value = new_group.and_then(|new_group| serde_json::to_value(new_group).ok());
+            self.change(path, compatibility, message, value, new_value);
+        }
+
+        for new_group in new.iter().filter(|new_group| find(old, new_group).is_none()) {
+            let value = serde_json::to_value(new_group).ok();
+            self.change(path, Compatibility::Forward, "exclusive group added", None, value);
+        }
+    }
+
+    fn property(
+        &mut self,
+        old: &'a Property,
+        new: &'a Property,
+        path: &str,
+    ) {
+        self.annotation(path, "title", old.title.as_ref(), new.title.as_ref());
+        self.annotation(path, "description", old.description.as_ref(), new.description.as_ref());
+        self.annotation(path, "comment", old.comment.as_ref(), new.comment.as_ref());
+
+        self.flag(path, "deprecated", old.deprecated, new.deprecated);
+        self.flag(path, "readOnly", old.read_only, new.read_only);
+        self.flag(path, "writeOnly", old.write_only, new.write_only);
+        self.annotation(path, "default", old.default.as_ref(), new.default.as_ref());
+        self.annotation(path, "examples", Some(&old.examples), Some(&new.examples));
+        self.r#const(path, old.r#const.as_ref(), new.r#const.as_ref());
+
+        self.ty(&old.ty, &new.ty, path);
+    }
+
+    // Compares ordered items, and added or removed items at the end.
+    fn list<T>(
+        &mut self,
+        old: &'a [T],
+        new: &'a [T],
+        path: &str,
+        added: Compatibility,
+        mut f: impl FnMut(&mut Self, &'a T, &'a T, &str),
+    ) {
+        for (i, (old, new)) in old.iter().zip(new).enumerate() {
+            f(self, old, new, &push(path, i));
+        }
+
+        match old.len().cmp(&new.len()) {
+            Ordering::Equal => {},
+            Ordering::Less => {
+                for i in old.len()..new.len() {
+                    self.change(&push(path, i), added, format!("item {} added", i), None, None);
+                }
+            },
+            Ordering::Greater => {
+                for i in new.len()..old.len() {
+                    self.change(&push(path, i), added.reversed(), format!("item {} removed", i), None, None);
+                }
+            },
+        }
+    }
+
+    fn lower_bound(
+        &mut self,
+        path: &str,
+        keyword: &str,
+        old: Option<Number>,
+        new: Option<Number>,
+    ) {
+        let compatibility = match (&old, &new) {
+            (None, None) => return,
+            (Some(old), Some(new)) => match compare(old, new) {
+                Ordering::Equal => return,
+                Ordering::Less => Compatibility::Forward,
+                Ordering::Greater => Compatibility::Backward,
+            },
+            (None, Some(_)) => Compatibility::Forward,
+            (Some(_), None) => Compatibility::Backward,
+        };
+        self.keyword(path, keyword, compatibility, old, new);
+    }
+
+    fn upper_bound(
+        &mut self,
+        path: &str,
+        keyword: &str,
+        old: Option<Number>,
+        new: Option<Number>,
+    ) {
+        let compatibility = match (&old, &new) {
+            (None, None) => return,
+            (Some(old), Some(new)) => match compare(old, new) {
+                Ordering::Equal => return,
+                Ordering::Less => Compatibility::Backward,
+                Ordering::Greater => Compatibility::Forward,
+            },
+            (None, Some(_)) => Compatibility::Forward,
+            (Some(_), None) => Compatibility::Backward,
+        };
+        self.keyword(path, keyword, compatibility, old, new);
+    }
+
+    // Adding a constraint restricts the values, and changing it can accept other values.
+    fn constraint(
+        &mut self,
+        path: &str,
+        keyword: &str,
+        old: Option<&String>,
+        new: Option<&String>,
+    ) {
+        let compatibility = match (old, new) {
+            (None, None) => return,
+            (Some(old), Some(new)) if old == new => return,
+            (Some(_), Some(_)) => Compatibility::Breaking,
+            (None, Some(_)) => Compatibility::Forward,
+            (Some(_), None) => Compatibility::Backward,
+        };
+        self.keyword(path, keyword, compatibility, old, new);
+    }
+
+    fn annotation<T: PartialEq + Serialize>(
+        &mut self,
+        path: &str,
+        keyword: &str,
+        old: Option<&T>,
+        new: Option<&T>,
+    ) {
+        if old != new {
+            self.keyword(path, keyword, Compatibility::Full, old, new);
+        }
+    }
+
+    fn flag(
+        &mut self,
+        path: &str,
+        keyword: &str,
+        old: Option<bool>,
+        new: Option<bool>,
+    ) {
+        let old = old == Some(true);
+        let new = new == Some(true);
+        if old != new {
+            self.keyword(path, keyword, Compatibility::Full, Some(old), Some(new));
+        }
+    }
+
+    fn r#const(
+        &mut self,
+        path: &str,
+        old: Option<&Value>,
+        new: Option<&Value>,
+    ) {
+        let compatibility = match (old, new) {
+            (old, new) if old == new => return,
+            (None, Some(_)) => Compatibility::Forward,
+            (Some(_), None) => Compatibility::Backward,
+            _ => Compatibility::Breaking,
+        };
+        self.keyword(path, "const", compatibility, old, new);
+    }
+
+    fn keyword(
+        &mut self,
+        path: &str,
+        keyword: &str,
+        compatibility: Compatibility,
+        old: Option<impl Serialize>,
+        new: Option<impl Serialize>,
+    ) {
+        let old = old.and_then(|old| serde_json::to_value(old).ok());
+        let new = new.and_then(|new| serde_json::to_value(new).ok());
+        let message = match (&old, &new) {
+            (Some(old), Some(new)) => format!("`{}` changed from {} to {}", keyword, old, new),
+            (Some(old), None) => format!("`{}` removed (was {})", keyword, old),
+            (None, Some(new)) => format!("`{}` added with {}", keyword, new),
+            (None, None) => format!("`{}` changed", keyword),
+        };
+        self.changes.push(Change {
+            path: push(path, keyword),
+            compatibility,
+            message,
+            old,
+            new,
+        });
+    }
+
+    fn change(
+        &mut self,
+        path: &str,
+        compatibility: Compatibility,
+        message: impl Into<String>,
+        old: Option<Value>,
+        new: Option<Value>,
+    ) {
+        self.changes.push(Change {
+            path: path.into(),
+            compatibility,
+            message: message.into(),
+            old,
+            new,
+        });
+    }
+}
+
+fn type_name(ty: &Type) -> &'static str {
+    match ty {
+        Type::String(_) => "string",
+        Type::Integer(_) => "integer",
+        Type::Number(_) => "number",
+        Type::Boolean => "boolean",
+        Type::Null => "null",
+        Type::Array(_) | Type::Tuple(_) => "array",
+        Type::Object(_) => "object",
+        Type::Enum(_) => "anyOf",
+        Type::AllOf(_) => "allOf",
+        Type::OneOf(_) => "oneOf",
+        Type::Not(_) => "not",
+        Type::Ref(_) => "$ref",
+        Type::Extra(keys) => type_name(&keys.ty),
+    }
+}
@@ -1,31 +1,61 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use std::fmt;
+
+mod conversion;
+
+pub(crate) use conversion::upgrade;
 
 /// Meta-schema versions. [Read more](https://json-schema.org/understanding-json-schema/reference/schema.html#schema)
 /// 
-#[derive(Debug, Serialize)]
+/// The chosen version also decides which keywords are used in the output.
+/// For example, definitions are written in `definitions` instead of `$defs` before draft 2019-09.
+/// 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub enum Draft {
     /// Add `"$schema": "http://json-schema.org/draft-04/schema#"`.
     /// 
     #[serde(rename = "http://json-schema.org/draft-04/schema#")]
+    #[serde(alias = "http://json-schema.org/draft-04/schema")]
     Draft4,
 
     /// Add `"$schema": "http://json-schema.org/draft-06/schema#"`.
     /// 
     #[serde(rename = "http://json-schema.org/draft-06/schema#")]
+    #[serde(alias = "http://json-schema.org/draft-06/schema")]
     Draft6,
 
     /// Add `"$schema": "http://json-schema.org/draft-07/schema#"`.
     /// 
     #[serde(rename = "http://json-schema.org/draft-07/schema#")]
+    #[serde(alias = "http://json-schema.org/draft-07/schema")]
     Draft7,
 
     /// Add `"$schema": "https://json-schema.org/draft/2019-09/schema"`.
     /// 
     #[serde(rename = "https://json-schema.org/draft/2019-09/schema")]
+    #[serde(alias = "https://json-schema.org/draft/2019-09/schema#")]
     Draft201909,
 
     /// Add `"$schema": "https://json-schema.org/draft/2020-12/schema"`.
     /// 
     #[serde(rename = "https://json-schema.org/draft/2020-12/schema")]
+    #[serde(alias = "https://json-schema.org/draft/2020-12/schema#")]
     Draft202012,
 }
+
+impl fmt::Display for Draft {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::Draft4      => "draft-04",
+            Self::Draft6      => "draft-06",
+            Self::Draft7      => "draft-07",
+            Self::Draft201909 => "draft 2019-09",
+            Self::Draft202012 => "draft 2020-12",
+        };
+        f.write_str(name)
+    }
+}
@@ -0,0 +1,341 @@
+use serde_json::{
+    Map,
+    Value,
+};
+
+use std::cmp::Ordering;
+
+use crate::{
+    Error,
+    Result,
+    compare,
+};
+
+use super::Draft;
+
+// Keywords whose value is a subschema.
+const SCHEMA_KEYWORDS: &[&str] = &[
+    "additionalItems",
+    "additionalProperties",
+    "contains",
+    "contentSchema",
+    "else",
+    "if",
+    "items",
+    "not",
+    "propertyNames",
+    "then",
+    "unevaluatedItems",
+    "unevaluatedProperties",
+];
+
+// Keywords whose value is an array of subschemas.
+const SCHEMA_ARRAY_KEYWORDS: &[&str] = &[
+    "allOf",
+    "anyOf",
+    "items",
+    "oneOf",
+    "prefixItems",
+];
+
+// Keywords whose value is a map of subschemas.
+const SCHEMA_MAP_KEYWORDS: &[&str] = &[
+    "$defs",
+    "definitions",
+    "dependencies",
+    "dependentSchemas",
+    "patternProperties",
+    "properties",
+];
+
+// Keywords that have no alternative before the given draft.
+const NEW_KEYWORDS: &[(&str, Draft)] = &[
+    ("const",                 Draft::Draft6),
+    ("contains",              Draft::Draft6),
+    ("propertyNames",         Draft::Draft6),
+    ("if",                    Draft::Draft7),
+    ("then",                  Draft::Draft7),
+    ("else",                  Draft::Draft7),
+    ("contentEncoding",       Draft::Draft7),
+    ("contentMediaType",      Draft::Draft7),
+    ("contentSchema",         Draft::Draft201909),
+    ("maxContains",           Draft::Draft201909),
+    ("minContains",           Draft::Draft201909),
+    ("unevaluatedItems",      Draft::Draft201909),
+    ("unevaluatedProperties", Draft::Draft201909),
+];
+
+impl Draft {
+    // Rschema generates schemas with the keywords of draft 2019-09.
+    // This rewrites them into the keywords of `self`.
+    pub(crate) fn convert(self, schema: &mut Value) -> Result<()> {
+        if let Value::Object(map) = schema {
+            *map = self.convert_keywords(std::mem::take(map))?;
+
+            for subschema in subschemas_mut(map) {
+                self.convert(subschema)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn convert_keywords(self, schema: Map<String, Value>) -> Result<Map<String, Value>> {
+        let unsupported = NEW_KEYWORDS
+            .iter()
+            .find(|(keyword, since)| self < *since && schema.contains_key(*keyword));
+        if let Some((keyword, _)) = unsupported {
+            return Err(Error::UnsupportedKeywordError {
+                keyword: keyword.to_string(),
+                draft: self,
+            });
+        }
+
+        let mut schema = schema;
+        if self == Draft::Draft4 {
+            schema = boolean_exclusive(schema, "minimum", "exclusiveMinimum", Ordering::Greater);
+            schema = boolean_exclusive(schema, "maximum", "exclusiveMaximum", Ordering::Less);
+        }
+        if self < Draft::Draft201909 {
+            schema = merge_dependencies(schema);
+        }
+
+        let has_tuple_items = matches!(schema.get("items"), Some(Value::Array(_)));
+
+        let converted = schema
+            .into_iter()
+            .map(|(keyword, value)| match keyword.as_str() {
+                "$defs" if self < Draft::Draft201909 => {
+                    ("definitions".into(), value)
+                },
+                "$ref" if self < Draft::Draft201909 => {
+                    let value = match value {
+                        Value::String(r) => match r.strip_prefix("#/$defs/") {
+                            Some(name) => Value::String(format!("#/definitions/{}", name)),
+                            None => Value::String(r),
+                        },
+                        value => value,
+                    };
+                    (keyword, value)
+                },
+                "$id" if self == Draft::Draft4 => {
+                    ("id".into(), value)
+                },
+                "items" if self >= Draft::Draft202012 && has_tuple_items => {
+                    ("prefixItems".into(), value)
+                },
+                "additionalItems" if self >= Draft::Draft202012 && has_tuple_items => {
+                    ("items".into(), value)
+                },
+                _ => (keyword, value),
+            })
+            .collect();
+
+        Ok(converted)
+    }
+}
+
+// Rewrites a schema written in the keywords of `draft` into the ones of draft 2019-09, in order to read it.
+// If `draft` is unknown, only the keywords that are not ambiguous are rewritten.
+pub(crate) fn upgrade(schema: &mut Value, draft: Option<Draft>) {
+    if let Value::Object(map) = schema {
+        *map = upgrade_keywords(std::mem::take(map), draft);
+
+        for subschema in subschemas_mut(map) {
+            upgrade(subschema, draft);
+        }
+    }
+}
+
+fn upgrade_keywords(schema: Map<String, Value>, draft: Option<Draft>) -> Map<String, Value> {
+    let mut schema = schema;
+    schema = numeric_exclusive(schema, "minimum", "exclusiveMinimum");
+    schema = numeric_exclusive(schema, "maximum", "exclusiveMaximum");
+    schema = split_dependencies(schema);
+
+    let has_defs = schema.contains_key("$defs");
+    let has_prefix_items = schema.contains_key("prefixItems");
+
+    schema
+        .into_iter()
+        .map(|(keyword, value)| match keyword.as_str() {
+            "definitions" if !has_defs => {
+                ("$defs".into(), value)
+            },
+            "$ref" => {
+                let value = match value {
+                    Value::String(r) => match r.strip_prefix("#/definitions/") {
+                        Some(name) => Value::String(format!("#/$defs/{}", name)),
+                        None => Value::String(r),
+                    },
+                    value => value,
+                };
+                (keyword, value)
+            },
+            "id" if draft == Some(Draft::Draft4) && value.is_string() => {
+                ("$id".into(), value)
+            },
+            "prefixItems" => {
+                ("items".into(), value)
+            },
+            "items" if has_prefix_items => {
+                ("additionalItems".into(), value)
+            },
+            _ => (keyword, value),
+        })
+        .collect()
+}
+
+pub(crate) fn subschemas_mut(schema: &mut Map<String, Value>) -> Vec<&mut Value> {
+    let mut subschemas = vec![];
+    for (keyword, value) in schema.iter_mut() {
+        let keyword = keyword.as_str();
+        if value.is_array() && SCHEMA_ARRAY_KEYWORDS.contains(&keyword) {
+            subschemas.extend(value.as_array_mut().into_iter().flatten());
+        } else if value.is_object() && SCHEMA_MAP_KEYWORDS.contains(&keyword) {
+            subschemas.extend(value.as_object_mut().into_iter().flat_map(Map::values_mut));
+        } else if SCHEMA_KEYWORDS.contains(&keyword) {
+            subschemas.push(value);
+        }
+    }
+    subschemas
+}
+
+// In draft 4, `exclusiveMinimum` and `exclusiveMaximum` are booleans which modify `minimum` and `maximum`.
+// If both of the bounds are given, the stricter one is kept.
+fn boolean_exclusive(
+    schema: Map<String, Value>,
+    inclusive: &str,
+    exclusive: &str,
+    stricter: Ordering,
+) -> Map<String, Value> {
+    let bound = match schema.get(exclusive) {
+        Some(Value::Number(n)) => n.clone(),
+        _ => return schema,
+    };
+    let has_inclusive = schema.contains_key(inclusive);
+    let inclusive_is_stricter = matches!(
+        schema.get(inclusive),
+        Some(Value::Number(n)) if compare(n, &bound) == stricter
+    );
+
+    let mut converted = Map::new();
+    for (keyword, value) in schema {
+        if keyword == inclusive && !inclusive_is_stricter {
+            converted.insert(keyword, Value::Number(bound.clone()));
+        } else if keyword == exclusive {
+            if inclusive_is_stricter {
+                continue;
+            }
+            if !has_inclusive {
+                converted.insert(inclusive.into(), Value::Number(bound.clone()));
+            }
+            converted.insert(keyword, Value::Bool(true));
+        } else {
+            converted.insert(keyword, value);
+        }
+    }
+    converted
+}
+
+// The reverse of `boolean_exclusive`.
+fn numeric_exclusive(
+    schema: Map<String, Value>,
+    inclusive: &str,
+    exclusive: &str,
+) -> Map<String, Value> {
+    let is_exclusive = match schema.get(exclusive) {
+        Some(Value::Bool(b)) => *b,
+        _ => return schema,
+    };
+
+    // The inclusive bound is kept as well, so that it is written back in place.
+    schema
+        .into_iter()
+        .flat_map(|(keyword, value)| {
+            if keyword == exclusive {
+                vec![]
+            } else if keyword == inclusive && is_exclusive {
+                vec![(exclusive.into(), value.clone()), (keyword, value)]
+            } else {
+                vec![(keyword, value)]
+            }
+        })
+        .collect()
+}
+
+// Before draft 2019-09, `dependentRequired` and `dependentSchemas` are written together in `dependencies`.
+// If a property has both, they are combined by `allOf`.
+fn merge_dependencies(schema: Map<String, Value>) -> Map<String, Value> {
+    if !schema.contains_key("dependentRequired") && !schema.contains_key("dependentSchemas") {
+        return schema;
+    }
+
+    let mut converted = Map::new();
+    for (keyword, value) in schema {
+        let dependents = match (keyword.as_str(), value) {
+            ("dependentRequired" | "dependentSchemas", Value::Object(dependents)) => dependents,
+            (_, value) => {
+                converted.insert(keyword, value);
+                continue;
+            },
+        };
+
+        let dependencies = converted
+            .entry("dependencies")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(dependencies) = dependencies {
+            for (name, dependent) in dependents {
+                let dependent = match dependencies.remove(&name) {
+                    Some(other) => serde_json::json!({
+                        "allOf": [as_schema(other), as_schema(dependent)],
+                    }),
+                    None => dependent,
+                };
+                dependencies.insert(name, dependent);
+            }
+        }
+    }
+    converted
+}
+
+// A list of the required properties is the same as a schema with `required`.
+fn as_schema(dependent: Value) -> Value {
+    match dependent {
+        Value::Array(required) => serde_json::json!({ "required": required }),
+        schema => schema,
+    }
+}
+
+// The reverse of `merge_dependencies`.
+fn split_dependencies(schema: Map<String, Value>) -> Map<String, Value> {
+    if !matches!(schema.get("dependencies"), Some(Value::Object(_))) {
+        return schema;
+    }
+
+    let mut converted = Map::new();
+    for (keyword, value) in schema {
+        let dependencies = match (keyword.as_str(), value) {
+            ("dependencies", Value::Object(dependencies)) => dependencies,
+            (_, value) => {
+                converted.insert(keyword, value);
+                continue;
+            },
+        };
+
+        for (name, dependent) in dependencies {
+            let keyword = if dependent.is_array() {
+                "dependentRequired"
+            } else {
+                "dependentSchemas"
+            };
+            let dependents = converted
+                .entry(keyword)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Value::Object(dependents) = dependents {
+                dependents.insert(name, dependent);
+            }
+        }
+    }
+    converted
+}
@@ -1,3 +1,5 @@
+use crate::Draft;
+
 /// This type represents all possible errors that can occur when generate or write JSON Schema string.
 /// 
 #[derive(Debug, thiserror::Error)]
@@ -7,4 +9,21 @@ pub enum Error {
 
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+
+    /// The schema uses a keyword that cannot be expressed in the chosen draft.
+    /// 
+    #[error("`{keyword}` cannot be expressed in {draft}")]
+    UnsupportedKeywordError {
+        keyword: String,
+        draft: Draft,
+    },
+
+    /// Different types are defined with the same name in `$defs`.
+    /// 
+    #[error("`$defs/{name}` is defined by both `{first}` and `{second}`")]
+    DefinitionsCollisionError {
+        name: String,
+        first: String,
+        second: String,
+    },
 }
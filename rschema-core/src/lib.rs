@@ -1,24 +1,160 @@
+mod annotations;
+mod constraints;
 mod definitions_map;
+mod diff;
 mod draft;
 mod error;
+mod recursion;
 mod schema;
 mod schematic;
+mod type_id;
+mod validation;
 
-pub use definitions_map::DefinitionsMap;
+pub use constraints::Constraints;
+pub use definitions_map::{
+    DefinitionsMap,
+    Origin,
+};
+pub use diff::{
+    Change,
+    Compatibility,
+    Diff,
+    diff,
+    diff_types,
+};
 pub use draft::Draft;
 pub use error::Error;
 pub use schema::{
     r#type::*,
+    Collision,
     Definitions,
+    Naming,
     Schema,
     Type,
 };
 pub use schematic::Schematic;
+pub use serde_json::Number;
+pub use validation::ValidationError;
 
 /// Alias for a `Result` with the error type `rschema::Error`.
 /// 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Keywords which are not handled by the schema model.
+/// 
+pub type Keywords = serde_json::Map<String, serde_json::Value>;
+
+// Used by the code which the derive macro generates. Not a public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use serde::Serialize;
+    pub use serde_json::Value;
+
+    pub use crate::annotations::{
+        Annotations,
+        Sealed,
+    };
+    pub use crate::recursion::{
+        inline_or_ref,
+        recursive_definition,
+    };
+
+    use crate::{
+        DefinitionsMap,
+        Naming,
+        Properties,
+        Schematic,
+        Type,
+    };
+
+    // `readOnly` of the properties whose type is `T`, given by the container attribute of `T`.
+    pub fn read_only<T: Schematic>() -> Option<bool> {
+        T::__annotations(Sealed(())).read_only.then_some(true)
+    }
+
+    // `writeOnly` of the properties whose type is `T`, given by the container attribute of `T`.
+    pub fn write_only<T: Schematic>() -> Option<bool> {
+        T::__annotations(Sealed(())).write_only.then_some(true)
+    }
+
+    // Defines `T` which reaches itself, unless nothing refers to it in the schema.
+    pub fn insert_recursive<T: Schematic>(
+        defs_map: &mut DefinitionsMap,
+        def: Type,
+    ) {
+        defs_map.insert_recursive::<T>(def);
+    }
+
+    // The name given by `defs` followed by the generic arguments of the type, such as `Page_User` of `Page<User>`.
+    pub fn generic_name(
+        name: &str,
+        ty_name: &str,
+    ) -> String {
+        // The lifetimes, which are written as `'_`, are not the arguments.
+        let args = ty_name
+            .split_once('<')
+            .map(|(_, args)| Naming::IdentWithGenerics.name(args))
+            .unwrap_or_default();
+        match args.as_str() {
+            "" => name.into(),
+            args => format!("{}_{}", name, args),
+        }
+    }
+
+    pub fn to_value<T: Serialize>(value: &T) -> Option<Value> {
+        serde_json::to_value(value).ok()
+    }
+
+    // Gives the properties without `default` the values of the same names in the serialized `value`.
+    pub fn fill_defaults<T: Serialize>(
+        properties: &mut Properties,
+        value: &T,
+    ) {
+        if let Some(Value::Object(mut values)) = to_value(value) {
+            for (name, property) in properties.iter_mut() {
+                if property.default.is_none() {
+                    property.default = values.remove(name);
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn compare(
+    a: &Number,
+    b: &Number,
+) -> std::cmp::Ordering {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return a.cmp(&b);
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return a.cmp(&b);
+    }
+    a.as_f64()
+        .partial_cmp(&b.as_f64())
+        .unwrap_or(std::cmp::Ordering::Equal)
+}
+
+// Distinguishes a keyword with `null` from no keyword, such as `"default": null`.
+pub(crate) fn deserialize_some<'de, D>(deserializer: D) -> std::result::Result<Option<serde_json::Value>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    serde::Deserialize::deserialize(deserializer).map(Some)
+}
+
 pub(crate) fn is_falsy(b: &Option<bool>) -> bool {
     *b != Some(true)
 }
+
+// Removes the keywords which `known` is written with, and returns the rest.
+pub(crate) fn unknown_keywords(
+    mut keywords: Keywords,
+    known: &impl serde::Serialize,
+) -> serde_json::Result<Keywords> {
+    if let serde_json::Value::Object(known) = serde_json::to_value(known)? {
+        keywords.retain(|keyword, _| !known.contains_key(keyword));
+    }
+
+    Ok(keywords)
+}
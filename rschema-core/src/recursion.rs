@@ -0,0 +1,61 @@
+use std::{
+    any::{
+        TypeId,
+        type_name,
+    },
+    cell::RefCell,
+};
+
+use crate::{
+    Type,
+    type_id::type_id,
+};
+
+thread_local! {
+    // The types whose schemas are being generated, and whether each of them is reached again.
+    static GENERATING: RefCell<Vec<(TypeId, bool)>> = const { RefCell::new(Vec::new()) };
+}
+
+// Generates the type of `T` with `f`, and tells whether `T` is reached again in `f`.
+// `None` is returned if `T` is already being generated, which is marked as reached.
+fn generate<T: ?Sized>(f: impl FnOnce() -> Type) -> Option<(Type, bool)> {
+    let id = type_id::<T>();
+    let is_generating = GENERATING.with(|generating| {
+        let mut generating = generating.borrow_mut();
+        match generating.iter_mut().find(|(other, _)| *other == id) {
+            Some((_, reached)) => {
+                *reached = true;
+                true
+            },
+            None => {
+                generating.push((id, false));
+                false
+            },
+        }
+    });
+    if is_generating {
+        return None;
+    }
+
+    let ty = f();
+    let reached = GENERATING.with(|generating| generating.borrow_mut().pop())
+        .is_some_and(|(_, reached)| reached);
+
+    Some((ty, reached))
+}
+
+// The type of `T` written inline, or a reference to `T` if it reaches itself, since it cannot be written inline then.
+pub fn inline_or_ref<T: ?Sized>(f: impl FnOnce() -> Type) -> Type {
+    match generate::<T>(f) {
+        Some((ty, false)) => ty,
+        _ => Type::Ref(type_name::<T>().into()),
+    }
+}
+
+// The definition of `T` which `inline_or_ref` refers to, or `None` if `T` does not reach itself.
+pub fn recursive_definition<T: ?Sized>(f: impl FnOnce() -> Type) -> Option<Type> {
+    match generate::<T>(f) {
+        Some((ty, true)) => Some(ty),
+        _ => None,
+    }
+}
@@ -1,17 +1,48 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de::Error as _,
+    ser::Error as _,
+};
+use serde_json::Value;
 
-use std::fs;
+use std::{
+    fs,
+    str::FromStr,
+};
 
 use crate::{
+    Constraints,
     Draft,
+    DefinitionsMap,
+    definitions_map::{
+        Collided,
+        Origin,
+        name_defs,
+        rename_defs,
+    },
+    Error,
+    Keywords,
     Result,
     Schematic,
+    ValidationError,
+    annotations::Sealed,
+    deserialize_some,
+    draft,
+    unknown_keywords,
+    validation::Validator,
 };
 
+mod collision;
 mod defs;
+mod naming;
 pub mod r#type;
 
+pub use collision::Collision;
 pub use defs::Definitions;
+pub use naming::Naming;
 pub use r#type::Type;
 
 /// This is a structure representing the JSON schema itself.
@@ -23,16 +54,33 @@ pub use r#type::Type;
 /// ## To JSON schema string
 /// 
 /// ```
-/// #[derive(Debug, Schematic)]
+/// use rschema_core::{
+///     Constraints,
+///     Property,
+///     Schema,
+///     Schematic,
+///     Type,
+/// };
+/// 
+/// #[derive(Debug)]
 /// struct Example {
-///     #[rschema(field(
-///         title = "Dummy",
-///         description = "Dummy field",
-///     ))]
 ///     dummy: String,
 /// }
 /// 
-/// fn main() -> rschema::Result<()> {
+/// impl Schematic for Example {
+///     fn schema(_constraints: Constraints) -> Type {
+///         let dummy = Property::new(Type::string())
+///             .title("Dummy")
+///             .description("Dummy field");
+/// 
+///         Type::object()
+///             .property("dummy", dummy)
+///             .deny_additional()
+///             .build()
+///     }
+/// }
+/// 
+/// fn main() -> rschema_core::Result<()> {
 ///     let schema_str = Schema::new::<Example>("Example")
 ///         .to_string()?;
 /// 
@@ -47,8 +95,24 @@ pub use r#type::Type;
 /// 
 /// Use [`to_string_pretty`](fn@Schema::to_string_pretty) to generate as a pretty-prited string.
 /// 
+/// ## From JSON schema string
+/// 
+/// An existing JSON schema can be read with [`from_str`](fn@Schema::from_str) or [`from_file`](fn@Schema::from_file).
+/// Keywords which Rschema does not handle are kept in `extra` of `Schema` and `Property`.
+/// The other subschemas, such as `items` of an array, are read as [`Type::Extra`] to keep them.
+/// 
+/// Empty schemas `{}`, boolean schemas except for `additionalProperties` and a list of types in `type`
+/// are not supported, and reading them fails.
+/// 
+/// ## With Serde
+/// 
+/// `Schema` also implements `Serialize`, such as for `serde_json::to_value` and `serde_json::to_writer`.
+/// Same as [`to_string`](fn@Schema::to_string), serializing fails if different types are defined with the same name under [`Collision::Error`].
+/// Unlike it, the keywords are not converted into the draft given by [`schema`](fn@Schema::schema).
+/// 
 /// 
 #[derive(Debug, Serialize)]
+#[serde(remote = "Self")]
 pub struct Schema {
     #[serde(rename = "$schema")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -58,33 +122,146 @@ pub struct Schema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
 
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub title: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#const: Option<Value>,
+
     #[serde(flatten)]
     ty: Type,
 
+    #[serde(flatten)]
+    pub extra: Keywords,
+
     #[serde(rename = "$defs")]
     #[serde(skip_serializing_if = "Definitions::is_empty")]
     defs: Definitions,
+
+    #[serde(skip)]
+    origins: Vec<Origin>,
+
+    // The names in `$defs` of the definitions in `origins`.
+    #[serde(skip)]
+    origin_names: Vec<String>,
+
+    #[serde(skip)]
+    collided: Option<Collided>,
+
+    #[serde(skip)]
+    collision: Collision,
 }
 
 impl Schema {
     /// Create a schema object from the given type `T`.
     /// 
+    /// Definitions of different types with the same name are numbered, such as `Config_2`.
+    /// Unless [`on_collision`](fn@Schema::on_collision) allows it, writing such a schema fails.
+    /// 
     pub fn new<T: Schematic>(title: &str) -> Self {
+        let mut ty = T::schema(Constraints::default());
+        let annotations = T::__annotations(Sealed(()));
+        let mut defs_map = T::definitions();
+        defs_map.prune(&mut ty);
+        let (defs, origins, collided) = defs_map.resolve(&mut ty, &Naming::default());
+
         Schema {
             schema: None,
             id: None,
             title: title.into(),
             description: None,
-            ty: T::__type_no_attr(),
-            defs: T::__defs(),
+            examples: annotations.examples,
+            r#const: annotations.r#const,
+            ty,
+            extra: Keywords::new(),
+            origin_names: defs.keys().cloned().collect(),
+            defs,
+            origins,
+            collided,
+            collision: Collision::default(),
         }
     }
 
+    /// Create a schema object from a type built at runtime, such as by [`Type::object`].
+    /// 
+    /// The type can also use the types implementing [`Schematic`], whose definitions are taken in by `defs_map`,
+    /// such as by [`DefinitionsMap::extend_ty`](crate::DefinitionsMap::extend_ty).
+    /// The definitions and the references to them are named in the same way as [`new`](fn@Schema::new).
+    /// 
+    /// To add a definition built at runtime, use [`define`](fn@Schema::define).
+    /// 
+    pub fn from_type(
+        title: &str,
+        ty: impl Into<Type>,
+        defs_map: DefinitionsMap,
+    ) -> Self {
+        let mut ty = ty.into();
+        let mut defs_map = defs_map;
+        defs_map.prune(&mut ty);
+        let (defs, origins, collided) = defs_map.resolve(&mut ty, &Naming::default());
+
+        Schema {
+            schema: None,
+            id: None,
+            title: title.into(),
+            description: None,
+            examples: vec![],
+            r#const: None,
+            ty,
+            extra: Keywords::new(),
+            origin_names: defs.keys().cloned().collect(),
+            defs,
+            origins,
+            collided,
+            collision: Collision::default(),
+        }
+    }
+
+    /// Read a schema from a JSON schema file.
+    /// 
+    /// # Errors
+    /// 
+    /// This call can fail if reading the file or its own `from_str` call fails.
+    /// 
+    pub fn from_file(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self> {
+        let schema_str = fs::read_to_string(path)?;
+        schema_str.parse()
+    }
+
+    /// The type of the root.
+    /// 
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+
+    /// The definitions in `$defs`.
+    /// 
+    pub fn defs(&self) -> &Definitions {
+        &self.defs
+    }
+
+    /// Define a type built at runtime in `$defs` with the given name.
+    /// 
+    /// It is referred to by `Type::Ref(name)`.
+    /// A definition with the same name is replaced.
+    /// 
+    pub fn define(
+        &mut self,
+        name: impl Into<String>,
+        def: impl Into<Type>,
+    ) -> &mut Self {
+        self.defs.insert(name.into(), def.into());
+        self
+    }
+
     /// Add a description about this schema.
     /// 
     pub fn description(
@@ -97,6 +274,8 @@ impl Schema {
 
     /// Specify `$schema`.
     /// 
+    /// The generated keywords also follow the given draft.
+    /// 
     pub fn schema(
         &mut self,
         schema: Draft,
@@ -115,14 +294,77 @@ impl Schema {
         self
     }
 
+    /// Specify what to do when different types are defined with the same name in `$defs`.
+    /// 
+    pub fn on_collision(
+        &mut self,
+        collision: Collision,
+    ) -> &mut Self {
+        self.collision = collision;
+        self
+    }
+
+    /// Specify how to name the types defined in `$defs` by `#[rschema(defs)]`.
+    /// 
+    /// The definitions and every reference to them are renamed.
+    /// 
+    /// This only renames the definitions collected from the Rust types.
+    /// The ones given otherwise, such as by [`define`](fn@Schema::define) or read from a string or a file, are left as they are.
+    /// 
+    pub fn naming(
+        &mut self,
+        naming: Naming,
+    ) -> &mut Self {
+        let (names, collided) = name_defs(&self.origins, &naming);
+
+        // The definitions are found by their current names, since the others can be anywhere in `$defs`.
+        let defs = std::mem::take(&mut self.defs);
+        let keys: Vec<_> = defs.keys().cloned().collect();
+        let new_keys = keys
+            .iter()
+            .map(|key| match self.origin_names.iter().position(|name| name == key) {
+                Some(i) => names[i].clone(),
+                None => key.clone(),
+            })
+            .collect();
+        let keys: Vec<_> = keys.iter().map(String::as_str).collect();
+        self.defs = rename_defs(&mut self.ty, defs.into_values(), &keys, new_keys);
+        self.origin_names = names;
+        self.collided = collided;
+
+        self
+    }
+
+    /// Validate a JSON value against this schema.
+    /// 
+    /// References are resolved through the definitions of this schema.
+    /// `format` is treated as an annotation and is not checked.
+    /// 
+    /// # Errors
+    /// 
+    /// This returns all of the errors found in the value.
+    /// 
+    pub fn validate(
+        &self,
+        instance: &serde_json::Value,
+    ) -> std::result::Result<(), Vec<ValidationError>> {
+        Validator::new(&self.defs).validate(self.r#const.as_ref(), &self.ty, instance)
+    }
+
     /// Generate a JSON schema string.
     /// 
+    /// The keywords follow the draft given by [`schema`](fn@Schema::schema).
+    /// If no draft is given, the keywords of draft 2019-09 are used.
+    /// 
     /// # Errors
     /// 
+    /// This fails if the schema has keywords that cannot be expressed in the given draft,
+    /// or if different types are defined with the same name in `$defs` under [`Collision::Error`].
+    /// 
     /// Internally calls `serde_json::to_string`, so this can fail if it fails. [Read more](https://docs.rs/serde_json/latest/serde_json/fn.to_string.html)
     /// 
     pub fn to_string(&self) -> Result<String> {
-        let schema_str = serde_json::to_string(self)?;
+        let schema_str = serde_json::to_string(&self.to_value()?)?;
         Ok(schema_str)
     }
 
@@ -130,13 +372,29 @@ impl Schema {
     /// 
     /// # Errors
     /// 
+    /// This fails if the schema has keywords that cannot be expressed in the given draft,
+    /// or if different types are defined with the same name in `$defs` under [`Collision::Error`].
+    /// 
     /// Internally calls `serde_json::to_string_pretty`, so this can fail if it fails. [Read more](https://docs.rs/serde_json/latest/serde_json/fn.to_string_pretty.html)
     /// 
     pub fn to_string_pretty(&self) -> Result<String> {
-        let schema_str = serde_json::to_string_pretty(self)?;
+        let schema_str = serde_json::to_string_pretty(&self.to_value()?)?;
         Ok(schema_str)
     }
 
+    fn to_value(&self) -> Result<serde_json::Value> {
+        if let (Collision::Error, Some(collided)) = (self.collision, &self.collided) {
+            return Err(collided.to_error());
+        }
+
+        let mut value = serde_json::to_value(self)?;
+        if let Some(draft) = self.schema {
+            draft.convert(&mut value)?;
+        }
+
+        Ok(value)
+    }
+
     /// Write a JSON schema string to a file.
     /// 
     /// # Errors
@@ -169,3 +427,107 @@ impl Schema {
         Ok(())
     }
 }
+
+impl Serialize for Schema {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let (Collision::Error, Some(collided)) = (self.collision, &self.collided) {
+            return Err(S::Error::custom(collided.to_error()));
+        }
+
+        Schema::serialize(self, serializer)
+    }
+}
+
+impl FromStr for Schema {
+    type Err = Error;
+
+    /// Read a schema from a JSON schema string.
+    /// 
+    /// The keywords of the draft given by `$schema` are read.
+    /// 
+    /// # Errors
+    /// 
+    /// This fails if the string is not a JSON schema that Rschema can represent,
+    /// such as one that has a `$ref` to an external document.
+    /// 
+    fn from_str(s: &str) -> Result<Self> {
+        let schema = serde_json::from_str(s)?;
+        Ok(schema)
+    }
+}
+
+impl<'de> Deserialize<'de> for Schema {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Metadata {
+            #[serde(rename = "$schema")]
+            schema: Option<Draft>,
+
+            #[serde(rename = "$id")]
+            id: Option<String>,
+
+            #[serde(default)]
+            title: String,
+
+            description: Option<String>,
+
+            #[serde(default)]
+            examples: Vec<Value>,
+
+            #[serde(default, deserialize_with = "deserialize_some")]
+            r#const: Option<Value>,
+
+            #[serde(rename = "$defs")]
+            #[serde(default)]
+            defs: Definitions,
+        }
+
+        let keywords = Keywords::deserialize(deserializer)?;
+        let mut value = Value::Object(keywords);
+
+        let draft = value
+            .get("$schema")
+            .map(Draft::deserialize)
+            .transpose()
+            .map_err(D::Error::custom)?;
+        draft::upgrade(&mut value, draft);
+
+        let Metadata {
+            schema,
+            id,
+            title,
+            description,
+            examples,
+            r#const,
+            defs,
+        } = Metadata::deserialize(&value).map_err(D::Error::custom)?;
+        let keywords = value.as_object().cloned().unwrap_or_default();
+        let ty = Type::from_keywords(keywords.clone()).map_err(D::Error::custom)?;
+
+        let mut schema = Schema {
+            schema,
+            id,
+            title,
+            description,
+            examples,
+            r#const,
+            ty,
+            extra: Keywords::new(),
+            defs,
+            origins: vec![],
+            origin_names: vec![],
+            collided: None,
+            collision: Collision::default(),
+        };
+
+        schema.extra = unknown_keywords(keywords, &schema).map_err(D::Error::custom)?;
+
+        Ok(schema)
+    }
+}
@@ -0,0 +1,13 @@
+/// What to do when different types are defined with the same name in `$defs`.
+/// 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Collision {
+    /// Generating or serializing the schema fails with [`Error::DefinitionsCollisionError`](crate::Error::DefinitionsCollisionError).
+    /// 
+    #[default]
+    Error,
+
+    /// The later definitions are numbered, such as `Config_2`, and their references follow.
+    /// 
+    Suffix,
+}
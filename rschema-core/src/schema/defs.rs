@@ -2,4 +2,4 @@ use indexmap::IndexMap;
 
 use super::Type;
 
-pub type Definitions = IndexMap<&'static str, Type>;
+pub type Definitions = IndexMap<String, Type>;
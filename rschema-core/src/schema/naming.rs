@@ -0,0 +1,73 @@
+use std::fmt;
+
+/// How to name the types defined in `$defs` by `#[rschema(defs)]`.
+/// 
+/// The names given by `#[rschema(defs = "name")]` are kept as they are.
+/// 
+#[derive(Default)]
+pub enum Naming {
+    /// The full path given by `std::any::type_name()`, such as `my_crate::models::Page<my_crate::models::User>`.
+    /// 
+    #[default]
+    FullPath,
+
+    /// The identifier of the type without the generic arguments, such as `Page`.
+    /// 
+    Ident,
+
+    /// The identifiers of the type and its generic arguments joined with `_`, such as `Page_User`.
+    /// 
+    IdentWithGenerics,
+
+    /// The name returned by the closure, which is given the full path.
+    /// 
+    Custom(Box<dyn Fn(&str) -> String + Send + Sync>),
+}
+
+impl fmt::Debug for Naming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FullPath          => f.write_str("FullPath"),
+            Self::Ident             => f.write_str("Ident"),
+            Self::IdentWithGenerics => f.write_str("IdentWithGenerics"),
+            Self::Custom(_)         => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl Naming {
+    /// Name the types with the given closure, which is given the full path.
+    /// 
+    pub fn custom(f: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        Self::Custom(Box::new(f))
+    }
+
+    pub(crate) fn name(&self, ty_name: &str) -> String {
+        match self {
+            Self::FullPath => ty_name.into(),
+            Self::Ident => {
+                let path = ty_name
+                    .split('<')
+                    .next()
+                    .unwrap_or(ty_name);
+                ident(path).into()
+            },
+            Self::IdentWithGenerics => {
+                ty_name
+                    .split(|c: char| "<>[](),;&* ".contains(c))
+                    .filter(|segment| !segment.is_empty() && *segment != "mut" && !segment.starts_with('\''))
+                    .map(ident)
+                    .collect::<Vec<_>>()
+                    .join("_")
+            },
+            Self::Custom(f) => f(ty_name),
+        }
+    }
+}
+
+// The last segment of a path, such as `User` of `my_crate::models::User`.
+fn ident(path: &str) -> &str {
+    path.rsplit("::")
+        .next()
+        .unwrap_or(path)
+}
@@ -1,27 +1,54 @@
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
+    de::Error as _,
 };
+use serde_json::Value;
 
+use crate::{
+    Keywords,
+    unknown_keywords,
+};
+
+mod all_of_keys;
 mod array_keys;
+mod builder;
 mod enum_keys;
+mod extra_keys;
+mod not_keys;
 mod numeric_keys;
 mod object_keys;
+mod one_of_keys;
 mod string_keys;
 mod tuple_keys;
 
+pub use all_of_keys::AllOfKeys;
 pub use array_keys::{
     ArrayKeys,
     Items,
 };
+pub use builder::{
+    ArrayBuilder,
+    NumericBuilder,
+    ObjectBuilder,
+    StringBuilder,
+};
 pub use enum_keys::EnumKeys;
+pub use extra_keys::ExtraKeys;
+pub use not_keys::NotKeys;
 pub use numeric_keys::NumericKeys;
 pub use object_keys::{
     AdditionalProperties,
+    Conditional,
+    ExclusiveGroup,
     ObjectKeys,
+    ObjectSubschema,
     Properties,
     Property,
 };
+pub use one_of_keys::OneOfKeys;
 pub use string_keys::StringKeys;
 pub use tuple_keys::TupleKeys;
 
@@ -33,9 +60,12 @@ pub enum Type {
     /// 
     String(StringKeys),
 
+    /// For an `integer` type property.
+    /// 
+    Integer(NumericKeys),
+
     /// For a `number` type property.
     /// 
-    // Integer(NumericKeys),
     Number(NumericKeys),
 
     /// For a `boolean` type property.
@@ -48,11 +78,11 @@ pub enum Type {
 
     /// For an `array` type property.
     /// 
-    Array(ArrayKeys),
+    Array(Box<ArrayKeys>),
 
     /// For an `object` type property.
     /// 
-    Object(ObjectKeys),
+    Object(Box<ObjectKeys>),
 
     /// For an `array` type property. In particular, it has unordered and composite type items.
     /// 
@@ -60,11 +90,31 @@ pub enum Type {
 
     /// For an `array` type property. In particular, it has ordered and composite type items.
     /// 
+    /// This is written as an `array` type which does not allow additional items.
+    /// 
     Tuple(TupleKeys),
 
+    /// For a property which must match all of the types.
+    /// 
+    AllOf(AllOfKeys),
+
+    /// For a property which must match exactly one of the types.
+    /// 
+    OneOf(OneOfKeys),
+
+    /// For a property which must not match the type.
+    /// 
+    Not(NotKeys),
+
     /// A reference to another schema.
     /// 
-    Ref(&'static str),
+    Ref(String),
+
+    /// A nested subschema read with keywords which Rschema does not handle.
+    /// 
+    /// `Schema` and `Property` keep such keywords in their own `extra` instead.
+    /// 
+    Extra(ExtraKeys),
 }
 
 macro_rules! keys_match_block {
@@ -124,14 +174,165 @@ impl Serialize for Type {
     {
         match self {
             Self::String(ref keys) => keys_match_block!( string, keys, serializer),
+            Self::Integer(ref keys) => keys_match_block!(integer, keys, serializer),
             Self::Number(ref keys) => keys_match_block!( number, keys, serializer),
             Self::Boolean          => unit_match_block!(boolean, serializer),
             Self::Null             => unit_match_block!(   null, serializer),
             Self::Array( ref keys) => keys_match_block!(  array, keys, serializer),
             Self::Object(ref keys) => keys_match_block!( object, keys, serializer),
             Self::Enum(  ref keys) => keys.serialize(serializer),
-            Self::Tuple( ref keys) => keys_match_block!(  tuple, keys, serializer),
+            Self::Tuple( ref keys) => keys_match_block!(  array, keys, serializer),
+            Self::AllOf( ref keys) => keys.serialize(serializer),
+            Self::OneOf( ref keys) => keys.serialize(serializer),
+            Self::Not(   ref keys) => keys.serialize(serializer),
             Self::Ref(ref def) => ref_match_block!(def, serializer),
+            Self::Extra( ref keys) => keys.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let keywords = Keywords::deserialize(deserializer)?;
+        let ty = Type::from_keywords(keywords.clone()).map_err(D::Error::custom)?;
+
+        // A nested subschema has no `extra` of its own, so its unknown keywords are kept by wrapping it.
+        let extra = unknown_keywords(keywords, &ty).map_err(D::Error::custom)?;
+        if extra.is_empty() {
+            Ok(ty)
+        } else {
+            Ok(Type::Extra(ExtraKeys {
+                ty: Box::new(ty),
+                extra,
+            }))
+        }
+    }
+}
+
+impl Type {
+    // Reads the keywords of the type only. The other keywords are left to the caller.
+    pub(crate) fn from_keywords(keywords: Keywords) -> serde_json::Result<Self> {
+        let keywords = Value::Object(keywords);
+
+        if let Some(r#ref) = keywords.get("$ref") {
+            let r#ref = String::deserialize(r#ref)?;
+            return match r#ref.strip_prefix("#/$defs/") {
+                Some(def) => Ok(Self::Ref(def.into())),
+                None => Err(serde_json::Error::custom(format!("unsupported reference `{}`", r#ref))),
+            };
+        }
+
+        let ty = match keywords.get("type") {
+            Some(Value::String(ty)) => ty.as_str(),
+            Some(_) => return Err(serde_json::Error::custom("`type` must be a string, since a list of types is not supported")),
+            None => return Self::from_composition(&keywords),
+        };
+        match ty {
+            "string"  => StringKeys::deserialize(&keywords).map(Self::String),
+            "integer" => NumericKeys::deserialize(&keywords).map(Self::Integer),
+            "number"  => NumericKeys::deserialize(&keywords).map(Self::Number),
+            "boolean" => Ok(Self::Boolean),
+            "null"    => Ok(Self::Null),
+            "array"   => Self::array_from_keywords(keywords),
+            "object"  => ObjectKeys::deserialize(&keywords).map(|keys| Self::Object(Box::new(keys))),
+            _ => Err(serde_json::Error::custom(format!("unknown type `{}`", ty))),
+        }
+    }
+
+    // An array without `items` is read as an empty tuple if no items are allowed, as Rschema writes it.
+    fn array_from_keywords(mut keywords: Value) -> serde_json::Result<Self> {
+        if let Some(keywords) = keywords.as_object_mut() {
+            if !keywords.contains_key("items") && keywords.get("maxItems") == Some(&Value::from(0)) {
+                keywords.insert("items".into(), Value::Array(vec![]));
+            }
+        }
+
+        ArrayKeys::deserialize(&keywords).map(|keys| Self::Array(Box::new(keys)))
+    }
+
+    // A schema without `type` is one of the compositions.
+    fn from_composition(keywords: &Value) -> serde_json::Result<Self> {
+        if keywords.get("anyOf").is_some() {
+            return EnumKeys::deserialize(keywords).map(Self::Enum);
+        }
+        if keywords.get("allOf").is_some() {
+            return AllOfKeys::deserialize(keywords).map(Self::AllOf);
+        }
+        if keywords.get("oneOf").is_some() {
+            return OneOfKeys::deserialize(keywords).map(Self::OneOf);
+        }
+        if keywords.get("not").is_some() {
+            return NotKeys::deserialize(keywords).map(Self::Not);
+        }
+
+        Err(serde_json::Error::custom("either `type`, `$ref`, `anyOf`, `allOf`, `oneOf` or `not` is required"))
+    }
+
+    // Calls `f` with the name of every reference in this type, including nested ones.
+    pub(crate) fn refs_mut(&mut self, f: &mut impl FnMut(&mut String)) {
+        fn properties_refs_mut(properties: &mut Properties, f: &mut impl FnMut(&mut String)) {
+            for property in properties.values_mut() {
+                property.ty.refs_mut(f);
+            }
+        }
+
+        match self {
+            Self::String(keys) => {
+                if let Some(ref mut ty) = keys.content_schema {
+                    ty.refs_mut(f);
+                }
+            },
+            Self::Integer(_) | Self::Number(_) | Self::Boolean | Self::Null => {},
+            Self::Array(keys) => {
+                match *keys.items {
+                    Items::Single(ref mut ty) => ty.refs_mut(f),
+                    Items::Tuple(ref mut properties) => {
+                        for property in properties {
+                            property.ty.refs_mut(f);
+                        }
+                    },
+                }
+                if let Some(ref mut ty) = keys.contains {
+                    ty.refs_mut(f);
+                }
+            },
+            Self::Object(keys) => {
+                properties_refs_mut(&mut keys.properties, f);
+                for ty in keys.pattern_properties.values_mut() {
+                    ty.refs_mut(f);
+                }
+                if let AdditionalProperties::Complex(ref mut ty) = *keys.additional_properties {
+                    ty.refs_mut(f);
+                }
+                if let Some(ref mut ty) = keys.property_names {
+                    ty.refs_mut(f);
+                }
+                for ty in keys.dependent_schemas.values_mut() {
+                    ty.refs_mut(f);
+                }
+                for conditional in keys.conditionals.iter_mut() {
+                    let subschemas = std::iter::once(&mut conditional.r#if)
+                        .chain(conditional.then.as_mut())
+                        .chain(conditional.r#else.as_mut());
+                    for subschema in subschemas {
+                        properties_refs_mut(&mut subschema.properties, f);
+                    }
+                }
+            },
+            Self::Enum(EnumKeys { any_of: tys }) |
+            Self::Tuple(TupleKeys { items: tys }) |
+            Self::AllOf(AllOfKeys { all_of: tys }) |
+            Self::OneOf(OneOfKeys { one_of: tys }) => {
+                for ty in tys {
+                    ty.refs_mut(f);
+                }
+            },
+            Self::Not(keys) => keys.not.refs_mut(f),
+            Self::Ref(name) => f(name),
+            Self::Extra(keys) => keys.ty.refs_mut(f),
         }
     }
 }
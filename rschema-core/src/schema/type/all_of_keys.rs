@@ -0,0 +1,14 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::Type;
+
+/// Keywords for a schema which must match all of the types in `allOf`.
+/// 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOfKeys {
+    pub all_of: Vec<Type>,
+}
@@ -1,15 +1,22 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
-use crate::is_falsy;
+use crate::{
+    Type,
+    is_falsy,
+};
 
 mod items;
 pub use items::Items;
 
 /// Keywords for an array type property.
 /// 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArrayKeys {
+    #[serde(flatten)]
     pub items: Box<Items>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,4 +27,17 @@ pub struct ArrayKeys {
 
     #[serde(skip_serializing_if = "is_falsy")]
     pub unique_items: Option<bool>,
+
+    /// The type which some of the items must match.
+    /// 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<Box<Type>>,
+
+    /// The minimum number of the items matching `contains`, which is 1 if not given.
+    /// 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_contains: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_contains: Option<usize>,
 }
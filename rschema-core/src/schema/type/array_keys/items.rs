@@ -1,18 +1,74 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de::Error as _,
+    ser::SerializeMap,
+};
+use serde_json::Value;
 
 use crate::{
+    Keywords,
     Property,
     Type,
 };
 
 /// Items of an array type or a tuple type property.
 /// 
-#[derive(Debug, Serialize)]
-#[serde(untagged)]
+/// For ordered items, additional items are not allowed.
+/// 
+#[derive(Debug)]
 pub enum Items {
     /// For an array type property with a single type items.
-    Single(Type),
+    Single(Box<Type>),
 
     /// For an array type property with ordered items, like a tuple struct.
     Tuple(Vec<Property>),
-}
\ No newline at end of file
+}
+
+impl Serialize for Items {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        match self {
+            Self::Single(ty) => {
+                map.serialize_entry("items", ty)?;
+            },
+            // An empty array is not a valid `items`, so no items are allowed only by `maxItems`.
+            Self::Tuple(properties) if properties.is_empty() => {},
+            Self::Tuple(properties) => {
+                map.serialize_entry("items", properties)?;
+                map.serialize_entry("additionalItems", &false)?;
+            },
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Items {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut keywords = Keywords::deserialize(deserializer)?;
+        let items = match keywords.remove("items") {
+            Some(items @ Value::Array(_)) => {
+                // Tuples published without `additionalItems` are read as closed ones.
+                match keywords.get("additionalItems") {
+                    None | Some(Value::Bool(false)) => {},
+                    Some(_) => {
+                        return Err(D::Error::custom("additional items of ordered items are not supported"));
+                    },
+                }
+                Vec::deserialize(items).map(Items::Tuple)
+            },
+            Some(items) => Type::deserialize(items).map(|ty| Items::Single(Box::new(ty))),
+            None => return Err(D::Error::missing_field("items")),
+        };
+
+        items.map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,43 @@
+use super::Type;
+
+mod array_builder;
+mod numeric_builder;
+mod object_builder;
+mod string_builder;
+
+pub use array_builder::ArrayBuilder;
+pub use numeric_builder::NumericBuilder;
+pub use object_builder::ObjectBuilder;
+pub use string_builder::StringBuilder;
+
+impl Type {
+    /// Start building a `string` type.
+    /// 
+    pub fn string() -> StringBuilder {
+        StringBuilder::new()
+    }
+
+    /// Start building an `integer` type.
+    /// 
+    pub fn integer() -> NumericBuilder {
+        NumericBuilder::new(true)
+    }
+
+    /// Start building a `number` type.
+    /// 
+    pub fn number() -> NumericBuilder {
+        NumericBuilder::new(false)
+    }
+
+    /// Start building an `array` type whose items are of the given type.
+    /// 
+    pub fn array(items: impl Into<Type>) -> ArrayBuilder {
+        ArrayBuilder::new(items.into())
+    }
+
+    /// Start building an `object` type. Additional properties are allowed unless denied.
+    /// 
+    pub fn object() -> ObjectBuilder {
+        ObjectBuilder::new()
+    }
+}
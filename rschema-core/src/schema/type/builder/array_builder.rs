@@ -0,0 +1,78 @@
+use crate::{
+    ArrayKeys,
+    Items,
+    Type,
+};
+
+/// A builder of an `array` type, started by [`Type::array`].
+/// 
+#[derive(Debug)]
+pub struct ArrayBuilder(ArrayKeys);
+
+impl From<ArrayBuilder> for Type {
+    fn from(builder: ArrayBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl ArrayBuilder {
+    pub(super) fn new(items: Type) -> Self {
+        Self(ArrayKeys {
+            items: Box::new(Items::Single(Box::new(items))),
+            min_items: None,
+            max_items: None,
+            unique_items: None,
+            contains: None,
+            min_contains: None,
+            max_contains: None,
+        })
+    }
+
+    /// Specify `minItems`.
+    /// 
+    pub fn min_items(mut self, min_items: usize) -> Self {
+        self.0.min_items = Some(min_items);
+        self
+    }
+
+    /// Specify `maxItems`.
+    /// 
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.0.max_items = Some(max_items);
+        self
+    }
+
+    /// Require the items to be unique, which is written as `uniqueItems`.
+    /// 
+    pub fn unique_items(mut self) -> Self {
+        self.0.unique_items = Some(true);
+        self
+    }
+
+    /// Specify `contains`.
+    /// 
+    pub fn contains(mut self, contains: impl Into<Type>) -> Self {
+        self.0.contains = Some(Box::new(contains.into()));
+        self
+    }
+
+    /// Specify `minContains`.
+    /// 
+    pub fn min_contains(mut self, min_contains: usize) -> Self {
+        self.0.min_contains = Some(min_contains);
+        self
+    }
+
+    /// Specify `maxContains`.
+    /// 
+    pub fn max_contains(mut self, max_contains: usize) -> Self {
+        self.0.max_contains = Some(max_contains);
+        self
+    }
+
+    /// Finish building.
+    /// 
+    pub fn build(self) -> Type {
+        Type::Array(Box::new(self.0))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    Number,
+    NumericKeys,
+    Type,
+};
+
+/// A builder of an `integer` or `number` type, started by [`Type::integer`] or [`Type::number`].
+/// 
+#[derive(Debug)]
+pub struct NumericBuilder {
+    integer: bool,
+    keys: NumericKeys,
+}
+
+impl From<NumericBuilder> for Type {
+    fn from(builder: NumericBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl NumericBuilder {
+    pub(super) fn new(integer: bool) -> Self {
+        Self {
+            integer,
+            keys: NumericKeys {
+                minimum: None,
+                maximum: None,
+                multiple_of: None,
+                exclusive_minimum: None,
+                exclusive_maximum: None,
+            },
+        }
+    }
+
+    /// Specify `minimum`.
+    /// 
+    pub fn minimum(mut self, minimum: impl Into<Number>) -> Self {
+        self.keys.minimum = Some(minimum.into());
+        self
+    }
+
+    /// Specify `maximum`.
+    /// 
+    pub fn maximum(mut self, maximum: impl Into<Number>) -> Self {
+        self.keys.maximum = Some(maximum.into());
+        self
+    }
+
+    /// Specify `multipleOf`.
+    /// 
+    pub fn multiple_of(mut self, multiple_of: impl Into<Number>) -> Self {
+        self.keys.multiple_of = Some(multiple_of.into());
+        self
+    }
+
+    /// Specify `exclusiveMinimum`.
+    /// 
+    pub fn exclusive_minimum(mut self, exclusive_minimum: impl Into<Number>) -> Self {
+        self.keys.exclusive_minimum = Some(exclusive_minimum.into());
+        self
+    }
+
+    /// Specify `exclusiveMaximum`.
+    /// 
+    pub fn exclusive_maximum(mut self, exclusive_maximum: impl Into<Number>) -> Self {
+        self.keys.exclusive_maximum = Some(exclusive_maximum.into());
+        self
+    }
+
+    /// Finish building.
+    /// 
+    pub fn build(self) -> Type {
+        if self.integer {
+            Type::Integer(self.keys)
+        } else {
+            Type::Number(self.keys)
+        }
+    }
+}
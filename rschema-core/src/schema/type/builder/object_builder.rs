@@ -0,0 +1,112 @@
+use crate::{
+    AdditionalProperties,
+    ObjectKeys,
+    Properties,
+    Property,
+    Type,
+};
+
+/// A builder of an `object` type, started by [`Type::object`].
+/// 
+#[derive(Debug)]
+pub struct ObjectBuilder(ObjectKeys);
+
+impl From<ObjectBuilder> for Type {
+    fn from(builder: ObjectBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl ObjectBuilder {
+    pub(super) fn new() -> Self {
+        Self(ObjectKeys {
+            properties: Properties::new(),
+            pattern_properties: Default::default(),
+            required: vec![],
+            additional_properties: AdditionalProperties::allowed(),
+            property_names: None,
+            min_properties: None,
+            max_properties: None,
+            dependent_required: Default::default(),
+            dependent_schemas: Default::default(),
+            conditionals: vec![],
+            exclusive_groups: vec![],
+            other_subschemas: vec![],
+        })
+    }
+
+    /// Add a property of the given type.
+    /// 
+    /// Give a [`Property`] to add it with annotations, such as `title`.
+    /// 
+    pub fn property(
+        mut self,
+        name: impl Into<String>,
+        property: impl Into<Property>,
+    ) -> Self {
+        self.0.properties.insert(name.into(), property.into());
+        self
+    }
+
+    /// Require the property with the given name.
+    /// 
+    pub fn required(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        if !self.0.required.contains(&name) {
+            self.0.required.push(name);
+        }
+        self
+    }
+
+    /// Allow the properties whose names match the regular expression, with the given type.
+    /// 
+    pub fn pattern_property(
+        mut self,
+        pattern: impl Into<String>,
+        ty: impl Into<Type>,
+    ) -> Self {
+        self.0.pattern_properties.insert(pattern.into(), ty.into());
+        self
+    }
+
+    /// Deny the properties not given, which is written as `"additionalProperties": false`.
+    /// 
+    pub fn deny_additional(mut self) -> Self {
+        self.0.additional_properties = Box::new(AdditionalProperties::Boolean(false));
+        self
+    }
+
+    /// Allow the properties not given, with the given type.
+    /// 
+    pub fn additional_properties(mut self, ty: impl Into<Type>) -> Self {
+        self.0.additional_properties = Box::new(AdditionalProperties::Complex(Box::new(ty.into())));
+        self
+    }
+
+    /// Specify `propertyNames`.
+    /// 
+    pub fn property_names(mut self, property_names: impl Into<Type>) -> Self {
+        self.0.property_names = Some(Box::new(property_names.into()));
+        self
+    }
+
+    /// Specify `minProperties`.
+    /// 
+    pub fn min_properties(mut self, min_properties: usize) -> Self {
+        self.0.min_properties = Some(min_properties);
+        self
+    }
+
+    /// Specify `maxProperties`.
+    /// 
+    pub fn max_properties(mut self, max_properties: usize) -> Self {
+        self.0.max_properties = Some(max_properties);
+        self
+    }
+
+    /// Finish building.
+    /// 
+    pub fn build(self) -> Type {
+        Type::Object(Box::new(self.0))
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    StringKeys,
+    Type,
+};
+
+/// A builder of a `string` type, started by [`Type::string`].
+/// 
+#[derive(Debug, Default)]
+pub struct StringBuilder(StringKeys);
+
+impl From<StringBuilder> for Type {
+    fn from(builder: StringBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl StringBuilder {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify `minLength`.
+    /// 
+    pub fn min_length(mut self, min_length: u64) -> Self {
+        self.0.min_length = Some(min_length);
+        self
+    }
+
+    /// Specify `maxLength`.
+    /// 
+    pub fn max_length(mut self, max_length: u64) -> Self {
+        self.0.max_length = Some(max_length);
+        self
+    }
+
+    /// Specify `pattern`.
+    /// 
+    pub fn pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.0.pattern = Some(pattern.into());
+        self
+    }
+
+    /// Specify `format`.
+    /// 
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.0.format = Some(format.into());
+        self
+    }
+
+    /// Specify `contentEncoding`.
+    /// 
+    pub fn content_encoding(mut self, content_encoding: impl Into<String>) -> Self {
+        self.0.content_encoding = Some(content_encoding.into());
+        self
+    }
+
+    /// Specify `contentMediaType`.
+    /// 
+    pub fn content_media_type(mut self, content_media_type: impl Into<String>) -> Self {
+        self.0.content_media_type = Some(content_media_type.into());
+        self
+    }
+
+    /// Specify `contentSchema`.
+    /// 
+    pub fn content_schema(mut self, content_schema: impl Into<Type>) -> Self {
+        self.0.content_schema = Some(Box::new(content_schema.into()));
+        self
+    }
+
+    /// Allow only the given values, which is written as `enum`.
+    /// 
+    pub fn enum_values<S: Into<String>>(mut self, values: impl IntoIterator<Item = S>) -> Self {
+        self.0.enm = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Finish building.
+    /// 
+    pub fn build(self) -> Type {
+        Type::String(self.0)
+    }
+}
@@ -1,4 +1,7 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use super::Type;
 
@@ -6,7 +9,7 @@ use super::Type;
 /// 
 /// Only an array of enum struct corresponds to `EnumKeys`.
 /// 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EnumKeys {
     pub any_of: Vec<Type>,
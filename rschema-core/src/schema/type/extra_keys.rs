@@ -0,0 +1,43 @@
+use serde::{
+    Serialize,
+    Serializer,
+    ser::{
+        Error as _,
+        SerializeMap,
+    },
+};
+use serde_json::Value;
+
+use crate::Keywords;
+
+use super::Type;
+
+/// A nested subschema which has keywords not handled by Rschema besides the ones of its type.
+/// 
+/// This is only made when a schema is read, so that the keywords are written back.
+/// 
+#[derive(Debug)]
+pub struct ExtraKeys {
+    pub ty: Box<Type>,
+
+    pub extra: Keywords,
+}
+
+impl Serialize for ExtraKeys {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Flattening the type here would nest the serializers without end, so it is written through a value.
+        let keywords = match serde_json::to_value(&self.ty).map_err(S::Error::custom)? {
+            Value::Object(keywords) => keywords,
+            _ => Keywords::new(),
+        };
+
+        let mut map = serializer.serialize_map(None)?;
+        for (keyword, value) in keywords.iter().chain(&self.extra) {
+            map.serialize_entry(keyword, value)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::Type;
+
+/// Keywords for a schema which must not match the type in `not`.
+/// 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotKeys {
+    pub not: Box<Type>,
+}
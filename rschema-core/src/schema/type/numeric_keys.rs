@@ -1,22 +1,28 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::Number;
 
 /// Keywords for a numeric type property.
 /// 
-#[derive(Debug, Serialize)]
+/// Each value keeps the form it was given in, so an integer is written as an integer and a decimal as a decimal.
+/// 
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NumericKeys {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub minimum: Option<i64>,
+    pub minimum: Option<Number>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum: Option<i64>,
+    pub maximum: Option<Number>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub multiple_of: Option<i64>,
+    pub multiple_of: Option<Number>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub exclusive_minimum: Option<i64>,
+    pub exclusive_minimum: Option<Number>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub exclusive_maximum: Option<i64>,
+    pub exclusive_maximum: Option<Number>,
 }
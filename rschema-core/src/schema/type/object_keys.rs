@@ -1,10 +1,29 @@
-use serde::Serialize;
+use indexmap::IndexMap;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_json::Value;
+
+use crate::{
+    Keywords,
+    Type,
+};
 
 mod additional_properties;
+mod conditional;
+mod exclusive_group;
+mod object_subschema;
 mod properties;
 mod property;
 
 pub use additional_properties::AdditionalProperties;
+pub use conditional::Conditional;
+pub use exclusive_group::ExclusiveGroup;
+pub use object_subschema::ObjectSubschema;
 pub use properties::Properties;
 pub use property::Property;
 
@@ -12,13 +31,144 @@ pub use property::Property;
 /// 
 /// Structs and struct-type variants are correspond to `ObjectKeys`.
 /// 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ObjectKeys {
+    #[serde(default)]
     pub properties: Properties,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The types of the properties whose names match the regular expressions of the keys.
+    /// 
+    /// The matched properties are not additional properties.
+    /// 
+    #[serde(default)]
+    pub pattern_properties: IndexMap<String, Type>,
+
+    #[serde(default)]
     pub required: Vec<String>,
 
+    #[serde(default = "AdditionalProperties::allowed")]
     pub additional_properties: Box<AdditionalProperties>,
+
+    /// The type which every property name must match as a string.
+    /// 
+    #[serde(default)]
+    pub property_names: Option<Box<Type>>,
+
+    #[serde(default)]
+    pub min_properties: Option<usize>,
+
+    #[serde(default)]
+    pub max_properties: Option<usize>,
+
+    /// The properties which are required when the property of the key is present.
+    /// 
+    /// This is written in `dependencies` before draft 2019-09.
+    /// 
+    #[serde(default)]
+    pub dependent_required: IndexMap<String, Vec<String>>,
+
+    /// The schemas which the object must match when the property of the key is present.
+    /// 
+    /// This is written in `dependencies` before draft 2019-09.
+    /// 
+    #[serde(default)]
+    pub dependent_schemas: IndexMap<String, Type>,
+
+    #[serde(flatten)]
+    #[serde(deserialize_with = "conditional::deserialize")]
+    pub conditionals: Vec<Conditional>,
+
+    #[serde(flatten)]
+    #[serde(deserialize_with = "exclusive_group::deserialize")]
+    pub exclusive_groups: Vec<ExclusiveGroup>,
+
+    /// The subschemas in `allOf` which are neither conditions nor exclusive groups, kept as they are read.
+    /// 
+    /// They are written back in `allOf` after the others, but not checked by [`Schema::validate`](crate::Schema::validate).
+    /// 
+    #[serde(flatten)]
+    #[serde(deserialize_with = "deserialize_other_subschemas")]
+    pub other_subschemas: Vec<Value>,
+}
+
+impl ObjectKeys {
+    // The number of the subschemas in `allOf`, or 1 if the only one is written in the object itself.
+    pub(crate) fn subschemas_len(&self) -> usize {
+        self.conditionals.len() + self.exclusive_groups.len() + self.other_subschemas.len()
+    }
+}
+
+impl Serialize for ObjectKeys {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("properties", &self.properties)?;
+        if !self.pattern_properties.is_empty() {
+            map.serialize_entry("patternProperties", &self.pattern_properties)?;
+        }
+        if !self.required.is_empty() {
+            map.serialize_entry("required", &self.required)?;
+        }
+        map.serialize_entry("additionalProperties", &self.additional_properties)?;
+        if let Some(ref property_names) = self.property_names {
+            map.serialize_entry("propertyNames", property_names)?;
+        }
+        if let Some(min_properties) = self.min_properties {
+            map.serialize_entry("minProperties", &min_properties)?;
+        }
+        if let Some(max_properties) = self.max_properties {
+            map.serialize_entry("maxProperties", &max_properties)?;
+        }
+        if !self.dependent_required.is_empty() {
+            map.serialize_entry("dependentRequired", &self.dependent_required)?;
+        }
+        if !self.dependent_schemas.is_empty() {
+            map.serialize_entry("dependentSchemas", &self.dependent_schemas)?;
+        }
+
+        // A single subschema is written in the object itself, and the others are combined by `allOf`.
+        // The other subschemas are always written in `allOf` as they are read.
+        let subschemas: Vec<Value> = self.conditionals
+            .iter()
+            .map(serde_json::to_value)
+            .chain(self.exclusive_groups.iter().map(serde_json::to_value))
+            .chain(self.other_subschemas.iter().cloned().map(Ok))
+            .collect::<serde_json::Result<_>>()
+            .map_err(serde::ser::Error::custom)?;
+        match subschemas.as_slice() {
+            [] => {},
+            [Value::Object(subschema)] if self.other_subschemas.is_empty() => {
+                for (keyword, value) in subschema {
+                    map.serialize_entry(keyword, value)?;
+                }
+            },
+            _ => map.serialize_entry("allOf", &subschemas)?,
+        }
+
+        map.end()
+    }
+}
+
+// Reads the subschemas in `allOf` which are left by `conditional::deserialize` and `exclusive_group::deserialize`.
+fn deserialize_other_subschemas<'de, D>(
+    deserializer: D,
+) -> Result<Vec<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let keywords = Keywords::deserialize(deserializer)?;
+
+    let other_subschemas = keywords
+        .get("allOf")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|subschema| subschema.get("if").is_none() && ExclusiveGroup::from_value(subschema).is_none())
+        .cloned()
+        .collect();
+
+    Ok(other_subschemas)
 }
@@ -1,15 +1,25 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::Type;
 
 /// Whether or not the object type property accepts additional properties, or what kind of properties it accepts.
 /// 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum AdditionalProperties {
     /// For objects with fixed properties, such as struct.
     Boolean(bool),
 
     /// For objects with undefined properties, such as HashMap.
-    Complex(Type),
+    Complex(Box<Type>),
+}
+
+impl AdditionalProperties {
+    // Additional properties are allowed if the keyword is omitted.
+    pub(crate) fn allowed() -> Box<Self> {
+        Box::new(Self::Boolean(true))
+    }
 }
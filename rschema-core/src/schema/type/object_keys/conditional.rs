@@ -0,0 +1,51 @@
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    de::Error as _,
+};
+use serde_json::Value;
+
+use crate::Keywords;
+
+use super::ObjectSubschema;
+
+/// A condition on an object type property, written with `if`, `then` and `else`.
+/// 
+/// If the object is valid against `if`, it must be valid against `then`. Otherwise, it must be valid against `else`.
+/// 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Conditional {
+    pub r#if: ObjectSubschema,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub then: Option<ObjectSubschema>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#else: Option<ObjectSubschema>,
+}
+
+// Conditions are written in the object itself, or in `allOf` with the exclusive groups.
+pub(super) fn deserialize<'de, D>(
+    deserializer: D,
+) -> Result<Vec<Conditional>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let keywords = Keywords::deserialize(deserializer)?;
+
+    if keywords.contains_key("if") {
+        let conditional = Conditional::deserialize(Value::Object(keywords))
+            .map_err(D::Error::custom)?;
+        return Ok(vec![conditional]);
+    }
+
+    keywords
+        .get("allOf")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|subschema| subschema.get("if").is_some())
+        .map(|subschema| Conditional::deserialize(subschema).map_err(D::Error::custom))
+        .collect()
+}
@@ -0,0 +1,119 @@
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de::Error as _,
+};
+use serde_json::{
+    Value,
+    json,
+};
+
+use crate::Keywords;
+
+/// Properties of an object type property which must not be present together.
+/// 
+/// If `required` is true, exactly one of them must be present. This is written with `oneOf`.
+/// Otherwise, at most one of them can be present. This is written with `not` and `anyOf` for each pair of them.
+/// 
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExclusiveGroup {
+    pub properties: Vec<String>,
+    pub required: bool,
+}
+
+impl ExclusiveGroup {
+    fn to_value(&self) -> Value {
+        if self.required {
+            let one_of: Vec<Value> = self.properties
+                .iter()
+                .map(|name| json!({ "required": [name] }))
+                .collect();
+            return json!({ "oneOf": one_of });
+        }
+
+        let mut pairs = vec![];
+        for (i, a) in self.properties.iter().enumerate() {
+            for b in &self.properties[i + 1..] {
+                pairs.push(json!({ "required": [a, b] }));
+            }
+        }
+        json!({ "not": { "anyOf": pairs } })
+    }
+
+    // Reads a subschema written by `to_value`. Any other subschema is not an exclusive group.
+    pub(super) fn from_value(value: &Value) -> Option<Self> {
+        let (subschemas, required) = match (value.pointer("/oneOf"), value.pointer("/not/anyOf")) {
+            (Some(Value::Array(one_of)), None) => (one_of, true),
+            (None, Some(Value::Array(any_of))) => (any_of, false),
+            _ => return None,
+        };
+        if value.as_object()?.len() != 1 {
+            return None;
+        }
+
+        let mut properties: Vec<String> = vec![];
+        for subschema in subschemas {
+            let subschema = subschema.as_object()?;
+            if subschema.len() != 1 {
+                return None;
+            }
+            for name in subschema.get("required")?.as_array()? {
+                let name = name.as_str()?;
+                if !properties.iter().any(|property| property == name) {
+                    properties.push(name.into());
+                }
+            }
+        }
+
+        let group = ExclusiveGroup {
+            properties,
+            required,
+        };
+        (&group.to_value() == value).then_some(group)
+    }
+}
+
+impl Serialize for ExclusiveGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExclusiveGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        ExclusiveGroup::from_value(&value)
+            .ok_or_else(|| D::Error::custom("not an exclusive group"))
+    }
+}
+
+// Exclusive groups are written in the object itself, or in `allOf` with the conditions.
+pub(super) fn deserialize<'de, D>(
+    deserializer: D,
+) -> Result<Vec<ExclusiveGroup>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let keywords = Keywords::deserialize(deserializer)?;
+
+    let inline = ["oneOf", "not"]
+        .iter()
+        .filter_map(|keyword| keywords.get_key_value(*keyword))
+        .map(|(keyword, value)| json!({ keyword: value }));
+    let all_of = keywords
+        .get("allOf")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .cloned();
+
+    Ok(inline.chain(all_of).filter_map(|value| ExclusiveGroup::from_value(&value)).collect())
+}
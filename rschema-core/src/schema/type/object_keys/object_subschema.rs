@@ -0,0 +1,19 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::Properties;
+
+/// Keywords which restrict the properties of an object without giving its type.
+/// 
+/// This is used in `if`, `then` and `else` of [`Conditional`](crate::Conditional).
+/// 
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ObjectSubschema {
+    #[serde(default, skip_serializing_if = "Properties::is_empty")]
+    pub properties: Properties,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+}
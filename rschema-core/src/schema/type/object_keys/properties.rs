@@ -1,5 +1,8 @@
 use indexmap::IndexMap;
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use std::ops::{
     Deref,
@@ -14,7 +17,7 @@ type PropertiesMap = IndexMap<String, Property>;
 /// 
 /// This is a map with entries of names and properties for each field of the structure and structure variant.
 /// 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct Properties(PropertiesMap);
 
 impl Deref for Properties {
@@ -35,4 +38,8 @@ impl Properties {
     pub fn new() -> Self {
         Properties(IndexMap::new())
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
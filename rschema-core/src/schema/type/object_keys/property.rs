@@ -1,13 +1,25 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    de::Error as _,
+};
+use serde_json::Value;
 
 use crate::{
+    Keywords,
     Type,
+    deserialize_some,
     is_falsy,
+    unknown_keywords,
 };
 
 /// One of the properties of an object type property.
 /// 
+/// Keywords which are not handled by Rschema are kept in `extra` when a schema is read.
+/// 
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Property {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -21,6 +33,131 @@ pub struct Property {
     #[serde(skip_serializing_if = "is_falsy")]
     pub deprecated: Option<bool>,
 
+    #[serde(skip_serializing_if = "is_falsy")]
+    pub read_only: Option<bool>,
+
+    #[serde(skip_serializing_if = "is_falsy")]
+    pub write_only: Option<bool>,
+
+    /// The default value. `Some(Value::Null)` is written as `"default": null`.
+    /// 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Value>,
+
+    /// The only allowed value. `Some(Value::Null)` is written as `"const": null`.
+    /// 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#const: Option<Value>,
+
     #[serde(flatten)]
     pub ty: Type,
+
+    #[serde(flatten)]
+    pub extra: Keywords,
+}
+
+impl<T: Into<Type>> From<T> for Property {
+    fn from(ty: T) -> Self {
+        Property::new(ty)
+    }
+}
+
+impl Property {
+    /// Create a property of the given type without annotations.
+    /// 
+    pub fn new(ty: impl Into<Type>) -> Self {
+        Property {
+            title: None,
+            description: None,
+            comment: None,
+            deprecated: None,
+            read_only: None,
+            write_only: None,
+            default: None,
+            examples: vec![],
+            r#const: None,
+            ty: ty.into(),
+            extra: Keywords::new(),
+        }
+    }
+
+    /// Specify `title`.
+    /// 
+    pub fn title(
+        mut self,
+        title: impl Into<String>,
+    ) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Specify `description`.
+    /// 
+    pub fn description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl<'de> Deserialize<'de> for Property {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Annotations {
+            title: Option<String>,
+            description: Option<String>,
+            comment: Option<String>,
+            deprecated: Option<bool>,
+            read_only: Option<bool>,
+            write_only: Option<bool>,
+            #[serde(default, deserialize_with = "deserialize_some")]
+            default: Option<Value>,
+            #[serde(default)]
+            examples: Vec<Value>,
+            #[serde(default, deserialize_with = "deserialize_some")]
+            r#const: Option<Value>,
+        }
+
+        let keywords = Keywords::deserialize(deserializer)?;
+        let value = Value::Object(keywords.clone());
+
+        let Annotations {
+            title,
+            description,
+            comment,
+            deprecated,
+            read_only,
+            write_only,
+            default,
+            examples,
+            r#const,
+        } = Annotations::deserialize(&value).map_err(D::Error::custom)?;
+        let ty = Type::from_keywords(keywords.clone()).map_err(D::Error::custom)?;
+
+        let mut property = Property {
+            title,
+            description,
+            comment,
+            deprecated,
+            read_only,
+            write_only,
+            default,
+            examples,
+            r#const,
+            ty,
+            extra: Keywords::new(),
+        };
+        property.extra = unknown_keywords(keywords, &property).map_err(D::Error::custom)?;
+
+        Ok(property)
+    }
 }
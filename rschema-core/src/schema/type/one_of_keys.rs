@@ -0,0 +1,16 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::Type;
+
+/// Keywords for a schema which must match exactly one of the types in `oneOf`.
+/// 
+/// Enums with `#[rschema(one_of)]` correspond to `OneOfKeys`.
+/// 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneOfKeys {
+    pub one_of: Vec<Type>,
+}
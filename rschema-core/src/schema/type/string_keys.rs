@@ -1,8 +1,13 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::Type;
 
 /// Keywords for a string type property.
 /// 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StringKeys {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -17,7 +22,22 @@ pub struct StringKeys {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// How the content is encoded in the string, such as `"base64"`.
+    /// 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<String>,
+
+    /// The media type of the content, such as `"application/json"`.
+    /// 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_media_type: Option<String>,
+
+    /// The type of the decoded content, which is given with `content_media_type`.
+    /// 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_schema: Option<Box<Type>>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[serde(rename = "enum")]
     pub enm: Vec<String>,
 }
\ No newline at end of file
@@ -1,14 +1,54 @@
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
 
 use super::Type;
 
 /// Keywords for a tuple type (a kind of array type) property.
 /// 
 /// Tuple type is a kind of array type, which has ordered items.
-/// Tuples and tuple structs are correspond to `TupleKeys`, additional properties are not allowed.
+/// Tuples and tuple structs are correspond to `TupleKeys`, additional items are not allowed.
 /// 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
 pub struct TupleKeys {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub items: Vec<Type>,
 }
+
+impl Serialize for TupleKeys {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = self.items.len();
+
+        let mut map = serializer.serialize_map(None)?;
+        // An empty array is not a valid `items`, so no items are allowed only by `maxItems`.
+        if len > 0 {
+            map.serialize_entry("items", &self.items)?;
+            map.serialize_entry("additionalItems", &false)?;
+        }
+        map.serialize_entry("minItems", &len)?;
+        map.serialize_entry("maxItems", &len)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TupleKeys {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Keys {
+            #[serde(default)]
+            items: Vec<Type>,
+        }
+
+        let Keys { items } = Keys::deserialize(deserializer)?;
+        Ok(TupleKeys { items })
+    }
+}
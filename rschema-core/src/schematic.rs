@@ -11,7 +11,7 @@ use std::collections::{
 use crate::{
     AdditionalProperties,
     ArrayKeys,
-    Definitions,
+    Constraints,
     DefinitionsMap,
     EnumKeys,
     Items,
@@ -21,12 +21,14 @@ use crate::{
     Property,
     Type,
     StringKeys,
+    annotations::{
+        Annotations,
+        Sealed,
+    },
 };
 
 /// A data structure that can provide any schema informations.
 /// 
-/// It is **deprecated** to implement this manually.
-/// 
 /// Rschema provides `Schematic` implementations for some Rust primitive and standard library types. All of these can be used to generate schema using Rschema.
 /// 
 /// ## Implementations of `Schematic` provided by Rschema
@@ -51,150 +53,133 @@ use crate::{
 ///   - HashSet\<T, H\>
 ///   - Vec\<T\>
 /// 
+/// ## Implementing `Schematic` manually
+/// 
+/// A type from another crate can implement this without the derive macro.
+/// [`schema`](Schematic::schema) is given the keywords of the field attributes, such as `#[rschema(min_length = 1)]`.
+/// 
+/// ```
+/// use rschema_core::{
+///     Constraints,
+///     Schema,
+///     Schematic,
+///     StringKeys,
+///     Type,
+/// };
+/// 
+/// #[derive(Debug)]
+/// struct Uuid(u128);
+/// 
+/// impl Schematic for Uuid {
+///     fn schema(constraints: Constraints) -> Type {
+///         Type::String(StringKeys {
+///             format: constraints.format.or_else(|| Some("uuid".into())),
+///             ..Default::default()
+///         })
+///     }
+/// }
+/// 
+/// let schema_str = Schema::new::<Uuid>("Session ID").to_string().unwrap();
+/// assert_eq!(
+///     schema_str,
+///     r#"{"title":"Session ID","type":"string","format":"uuid"}"#
+/// );
+/// ```
+/// 
+/// To define the type in `$defs`, return a reference by its type name from `schema`,
+/// and insert the definition in [`definitions`](Schematic::definitions).
+/// 
+/// ```
+/// use rschema_core::{
+///     Constraints,
+///     DefinitionsMap,
+///     Schema,
+///     Schematic,
+///     Type,
+/// };
+/// 
+/// struct Color;
+/// 
+/// impl Schematic for Color {
+///     fn schema(_constraints: Constraints) -> Type {
+///         Type::Ref(std::any::type_name::<Self>().into())
+///     }
+/// 
+///     fn definitions() -> DefinitionsMap {
+///         let mut defs_map = DefinitionsMap::new();
+///         defs_map.insert::<Self>("Color", String::schema(Constraints::default()));
+///         defs_map
+///     }
+/// }
+/// 
+/// let schema = Schema::new::<Vec<Color>>("Palette");
+/// assert_eq!(
+///     schema.to_string().unwrap(),
+///     r##"{"title":"Palette","type":"array","items":{"$ref":"#/$defs/Color"},"$defs":{"Color":{"type":"string"}}}"##
+/// );
+/// ```
+/// 
 pub trait Schematic {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type;
-
-    fn __type_no_attr() -> Type {
-        Self::__type(
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        )
-    }
+    /// Generate the type of this with the keywords of the field attributes.
+    /// 
+    /// The keywords which do not apply to the type can be ignored.
+    /// 
+    fn schema(constraints: Constraints) -> Type;
 
-    fn __defs() -> Definitions {
-        Self::__defs_map().build()
+    /// Collect the definitions in `$defs` which this type uses.
+    /// 
+    /// A type containing other types should take in their definitions with [`DefinitionsMap::extend_ty`].
+    /// 
+    fn definitions() -> DefinitionsMap {
+        DefinitionsMap::new()
     }
 
-    fn __defs_map() -> DefinitionsMap {
-        DefinitionsMap::new()
+    #[doc(hidden)]
+    fn __annotations(_: Sealed) -> Annotations {
+        Annotations::default()
     }
 }
 
 impl<T: Schematic> Schematic for &T {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        T::__type(
-            min_length,
-            max_length,
-            pattern,
-            format,
-            minimum,
-            maximum,
-            multiple_of,
-            exclusive_minimum,
-            exclusive_maximum,
-            min_items,
-            max_items,
-            unique_items,
-        )
+    fn schema(constraints: Constraints) -> Type {
+        T::schema(constraints)
     }
 
-    fn __type_no_attr() -> Type {
-        T::__type_no_attr()
+    fn definitions() -> DefinitionsMap {
+        T::definitions()
     }
 
-    fn __defs_map() -> DefinitionsMap {
-        DefinitionsMap::new()
+    fn __annotations(sealed: Sealed) -> Annotations {
+        T::__annotations(sealed).access()
     }
 }
 
 impl<T: Schematic> Schematic for &mut T {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        T::__type(
-            min_length,
-            max_length,
-            pattern,
-            format,
-            minimum,
-            maximum,
-            multiple_of,
-            exclusive_minimum,
-            exclusive_maximum,
-            min_items,
-            max_items,
-            unique_items,
-        )
+    fn schema(constraints: Constraints) -> Type {
+        T::schema(constraints)
     }
 
-    fn __type_no_attr() -> Type {
-        T::__type_no_attr()
+    fn definitions() -> DefinitionsMap {
+        T::definitions()
     }
 
-    fn __defs_map() -> DefinitionsMap {
-        DefinitionsMap::new()
+    fn __annotations(sealed: Sealed) -> Annotations {
+        T::__annotations(sealed).access()
     }
 }
 
 macro_rules! impl_for_str {
     ($ty:ty) => {
         impl Schematic for $ty {
-            fn __type(
-                min_length: Option<u64>,
-                max_length: Option<u64>,
-                pattern: Option<String>,
-                format: Option<String>,
-                minimum: Option<i64>,
-                maximum: Option<i64>,
-                multiple_of: Option<i64>,
-                exclusive_minimum: Option<i64>,
-                exclusive_maximum: Option<i64>,
-                min_items: Option<usize>,
-                max_items: Option<usize>,
-                unique_items: Option<bool>,
-            ) -> Type {
+            fn schema(constraints: Constraints) -> Type {
                 Type::String(StringKeys {
-                    min_length,
-                    max_length,
-                    pattern,
-                    format,
+                    min_length: constraints.min_length,
+                    max_length: constraints.max_length,
+                    pattern: constraints.pattern,
+                    format: constraints.format,
+                    content_encoding: constraints.content_encoding,
+                    content_media_type: constraints.content_media_type,
+                    content_schema: constraints.content_schema.map(Box::new),
                     enm: vec![],
                 })
             }
@@ -205,107 +190,76 @@ macro_rules! impl_for_str {
 impl_for_str!(&str);
 impl_for_str!(String);
 
-macro_rules! impl_for_num {
+macro_rules! impl_for_int {
+    ($ty:ty) => {
+        impl Schematic for $ty {
+            fn schema(constraints: Constraints) -> Type {
+                // The range of the type is used unless it is given explicitly.
+                Type::Integer(NumericKeys {
+                    minimum: constraints.minimum.or_else(|| Some(<$ty>::MIN.into())),
+                    maximum: constraints.maximum.or_else(|| Some(<$ty>::MAX.into())),
+                    multiple_of: constraints.multiple_of,
+                    exclusive_minimum: constraints.exclusive_minimum,
+                    exclusive_maximum: constraints.exclusive_maximum,
+                })
+            }
+        }
+    };
+}
+
+impl_for_int!(i8);
+impl_for_int!(i16);
+impl_for_int!(i32);
+impl_for_int!(i64);
+impl_for_int!(isize);
+impl_for_int!(u8);
+impl_for_int!(u16);
+impl_for_int!(u32);
+impl_for_int!(u64);
+impl_for_int!(usize);
+
+macro_rules! impl_for_float {
     ($ty:ty) => {
         impl Schematic for $ty {
-            fn __type(
-                min_length: Option<u64>,
-                max_length: Option<u64>,
-                pattern: Option<String>,
-                format: Option<String>,
-                minimum: Option<i64>,
-                maximum: Option<i64>,
-                multiple_of: Option<i64>,
-                exclusive_minimum: Option<i64>,
-                exclusive_maximum: Option<i64>,
-                min_items: Option<usize>,
-                max_items: Option<usize>,
-                unique_items: Option<bool>,
-            ) -> Type {
+            fn schema(constraints: Constraints) -> Type {
                 Type::Number(NumericKeys {
-                    minimum,
-                    maximum,
-                    multiple_of,
-                    exclusive_minimum,
-                    exclusive_maximum,
+                    minimum: constraints.minimum,
+                    maximum: constraints.maximum,
+                    multiple_of: constraints.multiple_of,
+                    exclusive_minimum: constraints.exclusive_minimum,
+                    exclusive_maximum: constraints.exclusive_maximum,
                 })
             }
         }
     };
 }
 
-impl_for_num!(i8);
-impl_for_num!(i16);
-impl_for_num!(i32);
-impl_for_num!(i64);
-impl_for_num!(isize);
-impl_for_num!(u8);
-impl_for_num!(u16);
-impl_for_num!(u32);
-impl_for_num!(u64);
-impl_for_num!(usize);
-impl_for_num!(f32);
-impl_for_num!(f64);
+impl_for_float!(f32);
+impl_for_float!(f64);
 
 impl Schematic for char {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
+    fn schema(constraints: Constraints) -> Type {
         Type::String(StringKeys {
             min_length: Some(1),
             max_length: Some(1),
-            pattern,
-            format,
+            pattern: constraints.pattern,
+            format: constraints.format,
+            content_encoding: constraints.content_encoding,
+            content_media_type: constraints.content_media_type,
+            content_schema: constraints.content_schema.map(Box::new),
             enm: vec![],
         })
     }
 }
 
 impl Schematic for bool {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
+    fn schema(constraints: Constraints) -> Type {
         Type::Boolean
     }
 }
 
 impl Schematic for () {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
+    fn schema(constraints: Constraints) -> Type {
         Type::Null
     }
 }
@@ -316,21 +270,8 @@ macro_rules! impls {
     // $c: Comma
     ( $n:expr, $( $t:tt $c:tt )* ) => {
         impl<$($t:Schematic $c)*> Schematic for ($($t $c)*) {
-            fn __type(
-                min_length: Option<u64>,
-                max_length: Option<u64>,
-                pattern: Option<String>,
-                format: Option<String>,
-                minimum: Option<i64>,
-                maximum: Option<i64>,
-                multiple_of: Option<i64>,
-                exclusive_minimum: Option<i64>,
-                exclusive_maximum: Option<i64>,
-                min_items: Option<usize>,
-                max_items: Option<usize>,
-                unique_items: Option<bool>,
-            ) -> Type {
-                Type::Array(ArrayKeys {
+            fn schema(constraints: Constraints) -> Type {
+                Type::Array(Box::new(ArrayKeys {
                     items: Box::new(Items::Tuple(vec![
                         $(
                             Property {
@@ -338,14 +279,31 @@ macro_rules! impls {
                                 description: None,
                                 comment: None,
                                 deprecated: None,
-                                ty: <$t as Schematic>::__type_no_attr(),
+                                read_only: None,
+                                write_only: None,
+                                default: None,
+                                examples: vec![],
+                                r#const: None,
+                                ty: <$t as Schematic>::schema(Constraints::default()),
+                                extra: Default::default(),
                             },
                         )*
                     ])),
                     min_items: Some($n),
                     max_items: Some($n),
-                    unique_items,
-                })
+                    unique_items: constraints.unique_items,
+                    contains: constraints.contains.map(Box::new),
+                    min_contains: constraints.min_contains,
+                    max_contains: constraints.max_contains,
+                }))
+            }
+
+            fn definitions() -> DefinitionsMap {
+                let mut defs_map = DefinitionsMap::new();
+                $(
+                    defs_map.extend_ty::<$t>();
+                )*
+                defs_map
             }
         }
     }
@@ -374,178 +332,145 @@ macro_rules! impls_tuple_for {
 impls_tuple_for!(12);
 
 impl<T: Schematic, const N: usize> Schematic for [T; N] {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        Type::Array(ArrayKeys {
-            items: Box::new(Items::Single(T::__type_no_attr())),
+    fn schema(constraints: Constraints) -> Type {
+        Type::Array(Box::new(ArrayKeys {
+            items: Box::new(Items::Single(Box::new(T::schema(Constraints::default())))),
             min_items: Some(N),
             max_items: Some(N),
-            unique_items,
-        })
+            unique_items: constraints.unique_items,
+            contains: constraints.contains.map(Box::new),
+            min_contains: constraints.min_contains,
+            max_contains: constraints.max_contains,
+        }))
+    }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.extend_ty::<T>();
+        defs_map
     }
 }
 
 impl<T: Schematic> Schematic for Option<T> {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
+    fn schema(constraints: Constraints) -> Type {
         Type::Enum(EnumKeys {
             any_of: vec![
-                T::__type_no_attr(),
+                T::schema(Constraints::default()),
                 Type::Null,
             ],
         })
     }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.extend_ty::<T>();
+        defs_map
+    }
+
+    fn __annotations(sealed: Sealed) -> Annotations {
+        T::__annotations(sealed).access()
+    }
 }
 
 impl<T: Schematic> Schematic for Box<T> {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        T::__type(
-            min_length,
-            max_length,
-            pattern,
-            format,
-            minimum,
-            maximum,
-            multiple_of,
-            exclusive_minimum,
-            exclusive_maximum,
-            min_items,
-            max_items,
-            unique_items,
-        )
+    fn schema(constraints: Constraints) -> Type {
+        T::schema(constraints)
+    }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.extend_ty::<T>();
+        defs_map
+    }
+
+    fn __annotations(sealed: Sealed) -> Annotations {
+        T::__annotations(sealed).access()
     }
 }
 
 impl<V: Schematic, S> Schematic for HashMap<String, V, S> {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        Type::Object(ObjectKeys {
+    fn schema(constraints: Constraints) -> Type {
+        Type::Object(Box::new(ObjectKeys {
             properties: Properties::new(),
+            pattern_properties: Default::default(),
             required: vec![],
             additional_properties: Box::new(
-                AdditionalProperties::Complex(V::__type_no_attr())
+                AdditionalProperties::Complex(Box::new(V::schema(Constraints::default())))
             ),
-        })
+            property_names: constraints.property_names.map(Box::new),
+            min_properties: constraints.min_properties,
+            max_properties: constraints.max_properties,
+            dependent_required: Default::default(),
+            dependent_schemas: Default::default(),
+            conditionals: vec![],
+            exclusive_groups: vec![],
+            other_subschemas: vec![],
+        }))
+    }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.extend_ty::<V>();
+        defs_map
     }
 }
 
 impl<T: Schematic, S> Schematic for HashSet<T, S> {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        Type::Array(ArrayKeys {
-            items: Box::new(Items::Single(T::__type_no_attr())),
-            min_items,
-            max_items,
+    fn schema(constraints: Constraints) -> Type {
+        Type::Array(Box::new(ArrayKeys {
+            items: Box::new(Items::Single(Box::new(T::schema(Constraints::default())))),
+            min_items: constraints.min_items,
+            max_items: constraints.max_items,
             unique_items: Some(true),
-        })
+            contains: constraints.contains.map(Box::new),
+            min_contains: constraints.min_contains,
+            max_contains: constraints.max_contains,
+        }))
+    }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.extend_ty::<T>();
+        defs_map
     }
 }
 
 impl<T: Schematic> Schematic for &[T] {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        Type::Array(ArrayKeys {
-            items: Box::new(Items::Single(T::__type_no_attr())),
-            min_items,
-            max_items,
-            unique_items,
-        })
+    fn schema(constraints: Constraints) -> Type {
+        Type::Array(Box::new(ArrayKeys {
+            items: Box::new(Items::Single(Box::new(T::schema(Constraints::default())))),
+            min_items: constraints.min_items,
+            max_items: constraints.max_items,
+            unique_items: constraints.unique_items,
+            contains: constraints.contains.map(Box::new),
+            min_contains: constraints.min_contains,
+            max_contains: constraints.max_contains,
+        }))
+    }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.extend_ty::<T>();
+        defs_map
     }
 }
 
 impl<T: Schematic> Schematic for Vec<T> {
-    fn __type(
-        min_length: Option<u64>,
-        max_length: Option<u64>,
-        pattern: Option<String>,
-        format: Option<String>,
-        minimum: Option<i64>,
-        maximum: Option<i64>,
-        multiple_of: Option<i64>,
-        exclusive_minimum: Option<i64>,
-        exclusive_maximum: Option<i64>,
-        min_items: Option<usize>,
-        max_items: Option<usize>,
-        unique_items: Option<bool>,
-    ) -> Type {
-        Type::Array(ArrayKeys {
-            items: Box::new(Items::Single(T::__type_no_attr())),
-            min_items,
-            max_items,
-            unique_items,
-        })
+    fn schema(constraints: Constraints) -> Type {
+        Type::Array(Box::new(ArrayKeys {
+            items: Box::new(Items::Single(Box::new(T::schema(Constraints::default())))),
+            min_items: constraints.min_items,
+            max_items: constraints.max_items,
+            unique_items: constraints.unique_items,
+            contains: constraints.contains.map(Box::new),
+            min_contains: constraints.min_contains,
+            max_contains: constraints.max_contains,
+        }))
+    }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.extend_ty::<T>();
+        defs_map
     }
 }
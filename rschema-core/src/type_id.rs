@@ -0,0 +1,31 @@
+use std::{
+    any::TypeId,
+    marker::PhantomData,
+};
+
+trait NonStaticAny {
+    fn type_id(&self) -> TypeId
+    where
+        Self: 'static;
+}
+
+impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+    fn type_id(&self) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<T>()
+    }
+}
+
+// `TypeId` of `T`, which does not have to be `'static`.
+// Lifetimes do not make types different in `TypeId`, so `View<'a>` is the same as `View<'static>`.
+pub(crate) fn type_id<T: ?Sized>() -> TypeId {
+    let phantom = PhantomData::<T>;
+    let phantom: &dyn NonStaticAny = &phantom;
+
+    // SAFETY: Only the lifetime of the trait object is extended, which `TypeId` does not depend on.
+    // `PhantomData` holds no value, so nothing outlives its lifetime through this.
+    let phantom: &(dyn NonStaticAny + 'static) = unsafe { std::mem::transmute(phantom) };
+    phantom.type_id()
+}
@@ -0,0 +1,660 @@
+use regex::Regex;
+use serde_json::{
+    Map,
+    Number,
+    Value,
+};
+
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt,
+    mem,
+};
+
+use crate::{
+    AdditionalProperties,
+    ArrayKeys,
+    Definitions,
+    Items,
+    NumericKeys,
+    ObjectKeys,
+    ObjectSubschema,
+    Property,
+    StringKeys,
+    Type,
+    compare,
+};
+
+/// An error found while validating an instance against a schema.
+/// 
+/// Both locations are JSON pointers. `schema_path` points to the keyword which the instance violates,
+/// and a referenced definition is pointed to under `/$defs`.
+/// 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The location of the invalid value in the instance.
+    pub instance_path: String,
+
+    /// The location of the violated keyword in the schema.
+    pub schema_path: String,
+
+    /// What is wrong with the value.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at `{}` (schema: `{}`)",
+            self.message,
+            self.instance_path,
+            self.schema_path,
+        )
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+pub(crate) struct Validator<'a> {
+    defs: &'a Definitions,
+    patterns: HashMap<&'a str, Option<Regex>>,
+    refs: Vec<(&'a str, String)>,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    pub(crate) fn new(defs: &'a Definitions) -> Self {
+        Validator {
+            defs,
+            patterns: HashMap::new(),
+            refs: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn validate(
+        mut self,
+        r#const: Option<&Value>,
+        ty: &'a Type,
+        instance: &Value,
+    ) -> Result<(), Vec<ValidationError>> {
+        self.check_const(r#const, instance, "", "");
+        self.check(ty, instance, "", "");
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    fn check(
+        &mut self,
+        ty: &'a Type,
+        instance: &Value,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        match (ty, instance) {
+            (Type::String(keys), Value::String(s)) => {
+                self.check_string(keys, s, instance_path, schema_path);
+            },
+            (Type::Integer(keys), Value::Number(n)) if is_integer(n) => {
+                self.check_numeric(keys, n, instance_path, schema_path);
+            },
+            (Type::Number(keys), Value::Number(n)) => {
+                self.check_numeric(keys, n, instance_path, schema_path);
+            },
+            (Type::Boolean, Value::Bool(_)) => {},
+            (Type::Null, Value::Null) => {},
+            (Type::Array(keys), Value::Array(items)) => {
+                self.check_array(keys, items, instance_path, schema_path);
+            },
+            (Type::Object(keys), Value::Object(properties)) => {
+                self.check_object(keys, properties, instance_path, schema_path);
+            },
+            (Type::Enum(keys), _) => {
+                let any_of_path = push(schema_path, "anyOf");
+                let matched = keys.any_of
+                    .iter()
+                    .enumerate()
+                    .any(|(i, ty)| {
+                        self.is_valid(ty, instance, instance_path, &push(&any_of_path, i))
+                    });
+                if !matched {
+                    self.error(instance_path, any_of_path, "does not match any of the types");
+                }
+            },
+            (Type::AllOf(keys), _) => {
+                let all_of_path = push(schema_path, "allOf");
+                for (i, ty) in keys.all_of.iter().enumerate() {
+                    self.check(ty, instance, instance_path, &push(&all_of_path, i));
+                }
+            },
+            (Type::OneOf(keys), _) => {
+                let one_of_path = push(schema_path, "oneOf");
+                let matched: Vec<usize> = keys.one_of
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, ty)| {
+                        self.is_valid(ty, instance, instance_path, &push(&one_of_path, i))
+                    })
+                    .map(|(i, _)| i)
+                    .collect();
+                match matched.len() {
+                    0 => self.error(instance_path, one_of_path, "does not match any of the types"),
+                    1 => {},
+                    _ => {
+                        let message = format!("matches more than one of the types {:?}", matched);
+                        self.error(instance_path, one_of_path, message);
+                    },
+                }
+            },
+            (Type::Not(keys), _) => {
+                let not_path = push(schema_path, "not");
+                if self.is_valid(&keys.not, instance, instance_path, &not_path) {
+                    self.error(instance_path, not_path, "matches the type which is not allowed");
+                }
+            },
+            (Type::Tuple(keys), Value::Array(items)) => {
+                let items_path = push(schema_path, "items");
+                for ((i, ty), item) in keys.items.iter().enumerate().zip(items) {
+                    self.check(ty, item, &push(instance_path, i), &push(&items_path, i));
+                }
+
+                let len = keys.items.len();
+                if items.len() > len {
+                    let message = format!("has {} items, but at most {} are allowed", items.len(), len);
+                    self.error(instance_path, push(schema_path, "additionalItems"), message);
+                } else if items.len() < len {
+                    let message = format!("has {} items, but at least {} are required", items.len(), len);
+                    self.error(instance_path, push(schema_path, "minItems"), message);
+                }
+            },
+            (Type::Ref(name), _) => {
+                self.check_ref(name, instance, instance_path, schema_path);
+            },
+            (Type::Extra(keys), _) => {
+                self.check(&keys.ty, instance, instance_path, schema_path);
+            },
+            (ty, instance) => {
+                let message = format!("expected {}, found {}", type_name(ty), instance_type_name(instance));
+                self.error(instance_path, push(schema_path, "type"), message);
+            },
+        }
+    }
+
+    fn check_property(
+        &mut self,
+        property: &'a Property,
+        instance: &Value,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        self.check_const(property.r#const.as_ref(), instance, instance_path, schema_path);
+        self.check(&property.ty, instance, instance_path, schema_path);
+    }
+
+    fn check_const(
+        &mut self,
+        r#const: Option<&Value>,
+        instance: &Value,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        if let Some(r#const) = r#const {
+            if instance != r#const {
+                let message = format!("is not equal to the constant {}", r#const);
+                self.error(instance_path, push(schema_path, "const"), message);
+            }
+        }
+    }
+
+    fn check_string(
+        &mut self,
+        keys: &'a StringKeys,
+        s: &str,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        let len = s.chars().count() as u64;
+
+        if let Some(min_length) = keys.min_length {
+            if len < min_length {
+                let message = format!("is shorter than {} characters", min_length);
+                self.error(instance_path, push(schema_path, "minLength"), message);
+            }
+        }
+
+        if let Some(max_length) = keys.max_length {
+            if len > max_length {
+                let message = format!("is longer than {} characters", max_length);
+                self.error(instance_path, push(schema_path, "maxLength"), message);
+            }
+        }
+
+        if let Some(ref pattern) = keys.pattern {
+            match self.is_match(pattern, s) {
+                Some(true) => {},
+                Some(false) => {
+                    let message = format!("does not match the pattern `{}`", pattern);
+                    self.error(instance_path, push(schema_path, "pattern"), message);
+                },
+                None => {
+                    let message = format!("cannot be checked with the invalid pattern `{}`", pattern);
+                    self.error(instance_path, push(schema_path, "pattern"), message);
+                },
+            }
+        }
+
+        if !keys.enm.is_empty() && !keys.enm.iter().any(|e| e == s) {
+            let message = format!("is not one of {:?}", keys.enm);
+            self.error(instance_path, push(schema_path, "enum"), message);
+        }
+    }
+
+    fn check_numeric(
+        &mut self,
+        keys: &NumericKeys,
+        n: &Number,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        let bounds = [
+            ("minimum", &keys.minimum, &[Ordering::Greater, Ordering::Equal][..], "less than"),
+            ("maximum", &keys.maximum, &[Ordering::Less, Ordering::Equal][..], "greater than"),
+            ("exclusiveMinimum", &keys.exclusive_minimum, &[Ordering::Greater][..], "less than or equal to"),
+            ("exclusiveMaximum", &keys.exclusive_maximum, &[Ordering::Less][..], "greater than or equal to"),
+        ];
+        for (keyword, bound, allowed, relation) in bounds {
+            if let Some(bound) = bound {
+                if !allowed.contains(&compare(n, bound)) {
+                    let message = format!("is {} {}", relation, bound);
+                    self.error(instance_path, push(schema_path, keyword), message);
+                }
+            }
+        }
+
+        if let Some(ref multiple_of) = keys.multiple_of {
+            if !is_multiple_of(n, multiple_of) {
+                let message = format!("is not a multiple of {}", multiple_of);
+                self.error(instance_path, push(schema_path, "multipleOf"), message);
+            }
+        }
+    }
+
+    fn check_array(
+        &mut self,
+        keys: &'a ArrayKeys,
+        items: &[Value],
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        let items_path = push(schema_path, "items");
+        match *keys.items {
+            Items::Single(ref ty) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.check(ty, item, &push(instance_path, i), &items_path);
+                }
+            },
+            Items::Tuple(ref properties) => {
+                for ((i, property), item) in properties.iter().enumerate().zip(items) {
+                    self.check_property(property, item, &push(instance_path, i), &push(&items_path, i));
+                }
+
+                if items.len() > properties.len() {
+                    let message = format!("has {} items, but at most {} are allowed", items.len(), properties.len());
+                    self.error(instance_path, push(schema_path, "additionalItems"), message);
+                }
+            },
+        }
+
+        if let Some(min_items) = keys.min_items {
+            if items.len() < min_items {
+                let message = format!("has {} items, but at least {} are required", items.len(), min_items);
+                self.error(instance_path, push(schema_path, "minItems"), message);
+            }
+        }
+
+        if let Some(max_items) = keys.max_items {
+            if items.len() > max_items {
+                let message = format!("has {} items, but at most {} are allowed", items.len(), max_items);
+                self.error(instance_path, push(schema_path, "maxItems"), message);
+            }
+        }
+
+        if keys.unique_items == Some(true) {
+            for (i, item) in items.iter().enumerate() {
+                if let Some(j) = items[..i].iter().position(|other| other == item) {
+                    let message = format!("is equal to the item at {}", j);
+                    self.error(&push(instance_path, i), push(schema_path, "uniqueItems"), message);
+                }
+            }
+        }
+
+        if let Some(ref contains) = keys.contains {
+            let contains_path = push(schema_path, "contains");
+            let len = items
+                .iter()
+                .enumerate()
+                .filter(|(i, item)| self.is_valid(contains, item, &push(instance_path, i), &contains_path))
+                .count();
+
+            let min_contains = keys.min_contains.unwrap_or(1);
+            if len < min_contains {
+                let keyword = if keys.min_contains.is_some() { "minContains" } else { "contains" };
+                let message = format!("has {} matching items, but at least {} are required", len, min_contains);
+                self.error(instance_path, push(schema_path, keyword), message);
+            }
+
+            if let Some(max_contains) = keys.max_contains {
+                if len > max_contains {
+                    let message = format!("has {} matching items, but at most {} are allowed", len, max_contains);
+                    self.error(instance_path, push(schema_path, "maxContains"), message);
+                }
+            }
+        }
+    }
+
+    fn check_object(
+        &mut self,
+        keys: &'a ObjectKeys,
+        properties: &Map<String, Value>,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        self.check_required(&keys.required, properties, instance_path, schema_path);
+
+        if let Some(min_properties) = keys.min_properties {
+            if properties.len() < min_properties {
+                let message = format!("has {} properties, but at least {} are required", properties.len(), min_properties);
+                self.error(instance_path, push(schema_path, "minProperties"), message);
+            }
+        }
+
+        if let Some(max_properties) = keys.max_properties {
+            if properties.len() > max_properties {
+                let message = format!("has {} properties, but at most {} are allowed", properties.len(), max_properties);
+                self.error(instance_path, push(schema_path, "maxProperties"), message);
+            }
+        }
+
+        let properties_path = push(schema_path, "properties");
+        let pattern_properties_path = push(schema_path, "patternProperties");
+        let additional_path = push(schema_path, "additionalProperties");
+        for (name, value) in properties {
+            let value_path = push(instance_path, name);
+
+            if let Some(ref property_names) = keys.property_names {
+                let name_instance = Value::String(name.clone());
+                self.check(property_names, &name_instance, &value_path, &push(schema_path, "propertyNames"));
+            }
+
+            let mut matches_pattern = false;
+            for (pattern, ty) in keys.pattern_properties.iter() {
+                let pattern_path = push(&pattern_properties_path, pattern);
+                match self.is_match(pattern, name) {
+                    Some(true) => {
+                        matches_pattern = true;
+                        self.check(ty, value, &value_path, &pattern_path);
+                    },
+                    Some(false) => {},
+                    None => {
+                        let message = format!("cannot be checked with the invalid pattern `{}`", pattern);
+                        self.error(&value_path, pattern_path, message);
+                    },
+                }
+            }
+
+            match (keys.properties.get(name), &*keys.additional_properties) {
+                (Some(property), _) => {
+                    self.check_property(property, value, &value_path, &push(&properties_path, name));
+                },
+                (None, _) if matches_pattern => {},
+                (None, AdditionalProperties::Boolean(true)) => {},
+                (None, AdditionalProperties::Boolean(false)) => {
+                    self.error(&value_path, additional_path.clone(), "is not an allowed property");
+                },
+                (None, AdditionalProperties::Complex(ty)) => {
+                    self.check(ty, value, &value_path, &additional_path);
+                },
+            }
+        }
+
+        let dependent_required_path = push(schema_path, "dependentRequired");
+        for (name, required) in keys.dependent_required.iter().filter(|(name, _)| properties.contains_key(*name)) {
+            for dependent in required.iter().filter(|dependent| !properties.contains_key(*dependent)) {
+                let message = format!("does not have the property `{}`, which `{}` requires", dependent, name);
+                self.error(instance_path, push(&dependent_required_path, name), message);
+            }
+        }
+
+        let dependent_schemas_path = push(schema_path, "dependentSchemas");
+        for (name, ty) in keys.dependent_schemas.iter().filter(|(name, _)| properties.contains_key(*name)) {
+            let instance = Value::Object(properties.clone());
+            self.check(ty, &instance, instance_path, &push(&dependent_schemas_path, name));
+        }
+
+        // A single subschema is written in the object itself, and the others in `allOf`.
+        let is_single = keys.subschemas_len() == 1;
+        let subschema_path = |i: usize| {
+            if is_single {
+                schema_path.to_owned()
+            } else {
+                push(&push(schema_path, "allOf"), i)
+            }
+        };
+
+        for (i, conditional) in keys.conditionals.iter().enumerate() {
+            let conditional_path = subschema_path(i);
+
+            let errors = mem::take(&mut self.errors);
+            self.check_subschema(&conditional.r#if, properties, instance_path, &push(&conditional_path, "if"));
+            let matched = self.errors.is_empty();
+            self.errors = errors;
+
+            let (keyword, subschema) = if matched {
+                ("then", &conditional.then)
+            } else {
+                ("else", &conditional.r#else)
+            };
+            if let Some(subschema) = subschema {
+                self.check_subschema(subschema, properties, instance_path, &push(&conditional_path, keyword));
+            }
+        }
+
+        for (i, group) in keys.exclusive_groups.iter().enumerate() {
+            let group_path = subschema_path(keys.conditionals.len() + i);
+            let present: Vec<&String> = group.properties
+                .iter()
+                .filter(|name| properties.contains_key(*name))
+                .collect();
+            let names = |names: &[&String]| {
+                names
+                    .iter()
+                    .map(|name| format!("`{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+
+            if present.len() > 1 {
+                let keyword = if group.required { "oneOf" } else { "not" };
+                let message = format!("has more than one of the exclusive properties {}", names(&present));
+                self.error(instance_path, push(&group_path, keyword), message);
+            } else if present.is_empty() && group.required {
+                let all: Vec<&String> = group.properties.iter().collect();
+                let message = format!("does not have any of the properties {}", names(&all));
+                self.error(instance_path, push(&group_path, "oneOf"), message);
+            }
+        }
+    }
+
+    fn check_subschema(
+        &mut self,
+        subschema: &'a ObjectSubschema,
+        properties: &Map<String, Value>,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        self.check_required(&subschema.required, properties, instance_path, schema_path);
+
+        let properties_path = push(schema_path, "properties");
+        for (name, property) in subschema.properties.iter() {
+            if let Some(value) = properties.get(name) {
+                self.check_property(property, value, &push(instance_path, name), &push(&properties_path, name));
+            }
+        }
+    }
+
+    fn check_required(
+        &mut self,
+        required: &[String],
+        properties: &Map<String, Value>,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        for name in required {
+            if !properties.contains_key(name) {
+                let message = format!("does not have the required property `{}`", name);
+                self.error(instance_path, push(schema_path, "required"), message);
+            }
+        }
+    }
+
+    fn check_ref(
+        &mut self,
+        name: &'a str,
+        instance: &Value,
+        instance_path: &str,
+        schema_path: &str,
+    ) {
+        let def = match self.defs.get(name) {
+            Some(def) => def,
+            None => {
+                let message = format!("refers to the missing definition `{}`", name);
+                self.error(instance_path, push(schema_path, "$ref"), message);
+                return;
+            },
+        };
+
+        // A definition which refers to itself without going deeper into the instance is checked only once.
+        let entry = (name, instance_path.to_owned());
+        if self.refs.contains(&entry) {
+            return;
+        }
+
+        self.refs.push(entry);
+        self.check(def, instance, instance_path, &push("/$defs", name));
+        self.refs.pop();
+    }
+
+    // `None` if the pattern is not a valid regular expression.
+    fn is_match(
+        &mut self,
+        pattern: &'a str,
+        s: &str,
+    ) -> Option<bool> {
+        self.patterns
+            .entry(pattern)
+            .or_insert_with(|| Regex::new(pattern).ok())
+            .as_ref()
+            .map(|regex| regex.is_match(s))
+    }
+
+    fn is_valid(
+        &mut self,
+        ty: &'a Type,
+        instance: &Value,
+        instance_path: &str,
+        schema_path: &str,
+    ) -> bool {
+        let errors = mem::take(&mut self.errors);
+        self.check(ty, instance, instance_path, schema_path);
+        let valid = self.errors.is_empty();
+        self.errors = errors;
+        valid
+    }
+
+    fn error(
+        &mut self,
+        instance_path: &str,
+        schema_path: String,
+        message: impl Into<String>,
+    ) {
+        self.errors.push(ValidationError {
+            instance_path: instance_path.into(),
+            schema_path,
+            message: message.into(),
+        });
+    }
+}
+
+// Appends a reference token to a JSON pointer.
+pub(crate) fn push(
+    path: &str,
+    token: impl fmt::Display,
+) -> String {
+    let token = token.to_string()
+        .replace('~', "~0")
+        .replace('/', "~1");
+    format!("{}/{}", path, token)
+}
+
+fn is_integer(n: &Number) -> bool {
+    n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+}
+
+pub(crate) fn is_multiple_of(
+    n: &Number,
+    multiple_of: &Number,
+) -> bool {
+    if let (Some(n), Some(m)) = (as_i128(n), as_i128(multiple_of)) {
+        return m != 0 && n % m == 0;
+    }
+
+    match (n.as_f64(), multiple_of.as_f64()) {
+        (Some(n), Some(m)) if m != 0.0 => {
+            let quotient = n / m;
+            (quotient - quotient.round()).abs() < 1e-9
+        },
+        _ => false,
+    }
+}
+
+fn as_i128(n: &Number) -> Option<i128> {
+    n.as_i64()
+        .map(i128::from)
+        .or_else(|| n.as_u64().map(i128::from))
+}
+
+fn type_name(ty: &Type) -> &'static str {
+    match ty {
+        Type::String(_) => "string",
+        Type::Integer(_) => "integer",
+        Type::Number(_) => "number",
+        Type::Boolean => "boolean",
+        Type::Null => "null",
+        Type::Array(_) | Type::Tuple(_) => "array",
+        Type::Object(_) => "object",
+        Type::Enum(_) |
+        Type::AllOf(_) |
+        Type::OneOf(_) |
+        Type::Not(_) |
+        Type::Ref(_) |
+        Type::Extra(_) => unreachable!("checked without the type keyword"),
+    }
+}
+
+fn instance_type_name(instance: &Value) -> &'static str {
+    match instance {
+        Value::String(_) => "string",
+        Value::Number(n) if is_integer(n) => "integer",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
@@ -5,18 +5,26 @@ use crate::Data;
 mod container_attr;
 mod impl_generics;
 mod type_generics;
+mod where_clause;
 
 pub use container_attr::{
+    Bound,
     ContainerAttr,
     Definitions,
+    Dependent,
     EnumAttr,
+    ExclusiveGroup,
     NewTypeStructAttr,
+    PatternProperty,
     StructAttr,
     TupleStructAttr,
     UnitStructAttr,
+    When,
+    find_field,
 };
 use impl_generics::ImplGenerics;
 use type_generics::TypeGenerics;
+use where_clause::WhereClause;
 
 #[derive(Debug)]
 pub struct Container<'a> {
@@ -36,7 +44,7 @@ impl<'a> Container<'a> {
     pub fn from_ast(
         input: &'a syn::DeriveInput,
     ) -> darling::Result<Self> {
-        let (attr, data) = match input.data {
+        let (attr, data): (ContainerAttr, _) = match input.data {
             syn::Data::Enum(ref data) => {
                 (
                     EnumAttr::from_derive_input(&input)?.into(),
@@ -74,6 +82,22 @@ impl<'a> Container<'a> {
             },
         };
 
+        let mut errors: Vec<darling::Error> = vec![];
+        if let Data::Struct(ref fields) = data {
+            errors.extend(attr.when.iter().filter_map(|when| when.check(fields).err()));
+            errors.extend(
+                attr.dependent
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, dependent)| dependent.check(fields, &attr.dependent[..i]).err()),
+            );
+            errors.extend(attr.exclusive_group.iter().filter_map(|group| group.check(fields).err()));
+        }
+        errors.extend(check_fields(&data));
+        if !errors.is_empty() {
+            return Err(darling::Error::multiple(errors));
+        }
+
         Ok(Self {
             attr,
             ident: &input.ident,
@@ -89,11 +113,39 @@ impl<'a> Container<'a> {
         ImplGenerics,
         TypeGenerics,
         syn::TypeGenerics,
-        Option<&syn::WhereClause>,
+        WhereClause,
     ) {
         let impl_generics = ImplGenerics(self);
         let type_generics = TypeGenerics(self);
-        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let where_clause = WhereClause(self);
         (impl_generics, type_generics, ty_generics, where_clause)
     }
-}
\ No newline at end of file
+
+}
+
+// The fields in `requires` and `exclusive_group` are checked in each struct and struct variant.
+fn check_fields(data: &Data) -> Vec<darling::Error> {
+    match data {
+        Data::Struct(fields) | Data::TupleStruct(fields) => {
+            fields
+                .iter()
+                .flat_map(|field| [field.check_requires(fields), field.check_exclusive_group(fields)])
+                .filter_map(|result| result.err())
+                .collect()
+        },
+        Data::NewTypeStruct(field) => {
+            [field.check_requires(&[]), field.check_exclusive_group(&[])]
+                .into_iter()
+                .filter_map(|result| result.err())
+                .collect()
+        },
+        Data::Enum(variants) => {
+            variants
+                .iter()
+                .flat_map(|variant| check_fields(&variant.data))
+                .collect()
+        },
+        Data::UnitStruct => vec![],
+    }
+}
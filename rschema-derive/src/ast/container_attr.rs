@@ -12,19 +12,34 @@ use crate::{
     is_falsy,
 };
 
+mod bound;
 mod enum_attr;
 mod definitions;
+mod dependent;
+mod exclusive_group;
 mod newtype_struct_attr;
+mod paths;
+mod pattern_property;
 mod struct_attr;
 mod tuple_struct_attr;
 mod unit_struct_attr;
+mod when;
 
+pub use bound::Bound;
 pub use enum_attr::EnumAttr;
 pub use definitions::Definitions;
+pub use dependent::Dependent;
+pub use exclusive_group::ExclusiveGroup;
 pub use newtype_struct_attr::NewTypeStructAttr;
+pub use paths::Paths;
+pub use pattern_property::PatternProperty;
 pub use struct_attr::StructAttr;
 pub use tuple_struct_attr::TupleStructAttr;
 pub use unit_struct_attr::UnitStructAttr;
+pub use when::{
+    When,
+    find_field,
+};
 
 #[derive(Debug, Default, FromAttributes, FromDeriveInput)]
 #[darling(attributes(rschema))]
@@ -32,12 +47,45 @@ pub struct ContainerAttr {
     #[darling(default)]
     pub additional_properties: Option<bool>,
 
+    #[darling(default)]
+    pub default: Option<bool>,
+
+    #[darling(default)]
+    pub one_of: Option<bool>,
+
+    #[darling(default)]
+    pub read_only: Option<bool>,
+
+    #[darling(default)]
+    pub write_only: Option<bool>,
+
     #[darling(default)]
     pub rename_all: Option<Case>,
 
     #[darling(default)]
     pub unique_items: Option<bool>,
 
+    #[darling(default, multiple)]
+    pub when: Vec<When>,
+
+    #[darling(default, multiple)]
+    pub dependent: Vec<Dependent>,
+
+    #[darling(default, multiple)]
+    pub exclusive_group: Vec<ExclusiveGroup>,
+
+    #[darling(default, multiple)]
+    pub pattern_properties: Vec<PatternProperty>,
+
+    #[darling(default, multiple)]
+    pub example: Vec<syn::ExprPath>,
+
+    #[darling(default)]
+    pub examples: Paths,
+
+    #[darling(default, rename = "const")]
+    pub const_value: Option<syn::ExprPath>,
+
     // bool   : 通常の使い方。std::any::type_name によって決められた名前で $defs に登録するが、
     //          その名前がユニーク性を保証していないことを明記する。可能であれば name を指定する
     //          こと、exportする構造体では名前の衝突を防ぐため name を指定しないことを記載。
@@ -46,13 +94,23 @@ pub struct ContainerAttr {
     // https://json-schema.org/understanding-json-schema/structuring.html#ref
     #[darling(default)]
     pub defs: Definitions,
+
+    #[darling(default)]
+    pub bound: Option<Bound>,
 }
 
 impl From<EnumAttr> for ContainerAttr {
     fn from(attr: EnumAttr) -> Self {
         ContainerAttr {
+            one_of: attr.one_of,
+            read_only: attr.read_only,
+            write_only: attr.write_only,
             rename_all: attr.rename_all,
+            example: attr.example,
+            examples: attr.examples,
+            const_value: attr.const_value,
             defs: attr.defs,
+            bound: attr.bound,
             ..Default::default()
         }
     }
@@ -61,7 +119,13 @@ impl From<EnumAttr> for ContainerAttr {
 impl From<NewTypeStructAttr> for ContainerAttr {
     fn from(attr: NewTypeStructAttr) -> Self {
         ContainerAttr {
+            read_only: attr.read_only,
+            write_only: attr.write_only,
+            example: attr.example,
+            examples: attr.examples,
+            const_value: attr.const_value,
             defs: attr.defs,
+            bound: attr.bound,
             ..Default::default()
         }
     }
@@ -71,8 +135,19 @@ impl From<StructAttr> for ContainerAttr {
     fn from(attr: StructAttr) -> Self {
         ContainerAttr {
             additional_properties: attr.additional_properties,
+            default: attr.default,
+            read_only: attr.read_only,
+            write_only: attr.write_only,
             rename_all: attr.rename_all,
+            when: attr.when,
+            dependent: attr.dependent,
+            exclusive_group: attr.exclusive_group,
+            pattern_properties: attr.pattern_properties,
+            example: attr.example,
+            examples: attr.examples,
+            const_value: attr.const_value,
             defs: attr.defs,
+            bound: attr.bound,
             ..Default::default()
         }
     }
@@ -81,16 +156,29 @@ impl From<StructAttr> for ContainerAttr {
 impl From<TupleStructAttr> for ContainerAttr {
     fn from(attr: TupleStructAttr) -> Self {
         ContainerAttr {
+            read_only: attr.read_only,
+            write_only: attr.write_only,
             unique_items: attr.unique_items,
+            example: attr.example,
+            examples: attr.examples,
+            const_value: attr.const_value,
             defs: attr.defs,
+            bound: attr.bound,
             ..Default::default()
         }
     }
 }
 
 impl From<UnitStructAttr> for ContainerAttr {
-    fn from(_attr: UnitStructAttr) -> Self {
-        ContainerAttr::default()
+    fn from(attr: UnitStructAttr) -> Self {
+        ContainerAttr {
+            read_only: attr.read_only,
+            write_only: attr.write_only,
+            example: attr.example,
+            examples: attr.examples,
+            const_value: attr.const_value,
+            ..Default::default()
+        }
     }
 }
 
@@ -98,9 +186,21 @@ impl ContainerAttribute for ContainerAttr {
     fn definitions(&self) -> &Definitions {
         &self.defs
     }
+
+    fn read_only(&self) -> bool {
+        !is_falsy(&self.read_only)
+    }
+
+    fn write_only(&self) -> bool {
+        !is_falsy(&self.write_only)
+    }
 }
 
 impl EnumAttribute for ContainerAttr {
+    fn one_of(&self) -> bool {
+        !is_falsy(&self.one_of)
+    }
+
     fn rename_all(&self) -> Option<Case> {
         self.rename_all
     }
@@ -111,9 +211,29 @@ impl StructAttribute for ContainerAttr {
         !is_falsy(&self.additional_properties)
     }
 
+    fn fill_defaults(&self) -> bool {
+        !is_falsy(&self.default)
+    }
+
     fn rename_all(&self) -> Option<Case> {
         self.rename_all
     }
+
+    fn when(&self) -> &[When] {
+        &self.when
+    }
+
+    fn dependent(&self) -> &[Dependent] {
+        &self.dependent
+    }
+
+    fn exclusive_groups(&self) -> &[ExclusiveGroup] {
+        &self.exclusive_group
+    }
+
+    fn pattern_properties(&self) -> &[PatternProperty] {
+        &self.pattern_properties
+    }
 }
 
 impl TupleStructAttribute for ContainerAttr {
@@ -0,0 +1,22 @@
+use darling::FromMeta;
+use syn::{
+    Token,
+    WherePredicate,
+    parse::Parser,
+    punctuated::Punctuated,
+};
+
+// The where predicates used instead of the `Schematic` bounds on the type parameters.
+// 
+// e.x.) `#[rschema(bound = "T: Schematic + Default")]`
+#[derive(Debug)]
+pub struct Bound(pub Vec<WherePredicate>);
+
+impl FromMeta for Bound {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        let predicates = Punctuated::<WherePredicate, Token![,]>::parse_terminated
+            .parse_str(value)
+            .map_err(|e| darling::Error::custom(format!("Invalid `bound`: {}", e)))?;
+        Ok(Bound(predicates.into_iter().collect()))
+    }
+}
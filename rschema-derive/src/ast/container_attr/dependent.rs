@@ -0,0 +1,40 @@
+use darling::{
+    FromMeta,
+    util::SpannedValue,
+};
+
+use crate::{
+    Field,
+    find_field,
+};
+
+// A schema which the object must match when the field is present.
+// 
+// e.x.) `#[rschema(dependent(field = "tls_cert", schema = "TlsConfig"))]`
+#[derive(Debug, FromMeta)]
+pub struct Dependent {
+    pub field: SpannedValue<String>,
+    pub schema: syn::TypePath,
+}
+
+impl Dependent {
+    // The name must be of a field in the schema, so that it can be renamed.
+    // A field can have only one schema, so it must not be given in the `earlier` ones.
+    pub fn check(
+        &self,
+        fields: &[Field],
+        earlier: &[Dependent],
+    ) -> darling::Result<()> {
+        if find_field(fields, &self.field).is_none() {
+            let message = format!("Unknown field `{}` in `dependent`", *self.field);
+            return Err(darling::Error::custom(message).with_span(&self.field.span()));
+        }
+
+        if earlier.iter().any(|dependent| *dependent.field == *self.field) {
+            let message = format!("Duplicated field `{}` in `dependent`", *self.field);
+            return Err(darling::Error::custom(message).with_span(&self.field.span()));
+        }
+
+        Ok(())
+    }
+}
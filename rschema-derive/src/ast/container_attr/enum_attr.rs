@@ -1,7 +1,9 @@
 use darling::FromDeriveInput;
 
 use super::{
+    Bound,
     Case,
+    Paths,
     definitions::{
         Definitions,
         and_then,
@@ -11,10 +13,31 @@ use super::{
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(rschema))]
 pub struct EnumAttr {
+    #[darling(default)]
+    pub one_of: Option<bool>,
+
+    #[darling(default)]
+    pub read_only: Option<bool>,
+
+    #[darling(default)]
+    pub write_only: Option<bool>,
+
     #[darling(default)]
     pub rename_all: Option<Case>,
 
+    #[darling(default, multiple)]
+    pub example: Vec<syn::ExprPath>,
+
+    #[darling(default)]
+    pub examples: Paths,
+
+    #[darling(default, rename = "const")]
+    pub const_value: Option<syn::ExprPath>,
+
     #[darling(default)]
     #[darling(and_then = "and_then")]
     pub defs: Definitions,
+
+    #[darling(default)]
+    pub bound: Option<Bound>,
 }
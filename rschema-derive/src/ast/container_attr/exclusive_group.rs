@@ -0,0 +1,42 @@
+use darling::{
+    FromMeta,
+    util::SpannedValue,
+};
+
+use crate::{
+    Field,
+    is_falsy,
+};
+
+// Options of an exclusive group, whose fields are given `#[rschema(exclusive_group = "auth")]`.
+// 
+// e.x.) `#[rschema(exclusive_group(name = "auth", required))]`
+#[derive(Debug, FromMeta)]
+pub struct ExclusiveGroup {
+    pub name: SpannedValue<String>,
+
+    #[darling(default)]
+    pub required: Option<bool>,
+}
+
+impl ExclusiveGroup {
+    pub fn required(&self) -> bool {
+        !is_falsy(&self.required)
+    }
+
+    // The name must be of a group given to the fields.
+    pub fn check(
+        &self,
+        fields: &[Field],
+    ) -> darling::Result<()> {
+        let exists = fields
+            .iter()
+            .any(|field| field.attr.exclusive_group.as_ref() == Some(&*self.name));
+        if exists {
+            Ok(())
+        } else {
+            let message = format!("Unknown group `{}` in `exclusive_group`", *self.name);
+            Err(darling::Error::custom(message).with_span(&self.name.span()))
+        }
+    }
+}
@@ -1,14 +1,36 @@
 use darling::FromDeriveInput;
 
-use super::definitions::{
-    Definitions,
-    and_then,
+use super::{
+    Bound,
+    Paths,
+    definitions::{
+        Definitions,
+        and_then,
+    },
 };
 
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(rschema))]
 pub struct NewTypeStructAttr {
+    #[darling(default)]
+    pub read_only: Option<bool>,
+
+    #[darling(default)]
+    pub write_only: Option<bool>,
+
+    #[darling(default, multiple)]
+    pub example: Vec<syn::ExprPath>,
+
+    #[darling(default)]
+    pub examples: Paths,
+
+    #[darling(default, rename = "const")]
+    pub const_value: Option<syn::ExprPath>,
+
     #[darling(default)]
     #[darling(and_then = "and_then")]
     pub defs: Definitions,
+
+    #[darling(default)]
+    pub bound: Option<Bound>,
 }
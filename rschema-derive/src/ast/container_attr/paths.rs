@@ -0,0 +1,22 @@
+use darling::FromMeta;
+
+// Paths to the functions given in a list.
+// 
+// e.x.) `#[rschema(examples("default_config", "minimal_config"))]`
+#[derive(Debug, Default)]
+pub struct Paths(pub Vec<syn::ExprPath>);
+
+impl FromMeta for Paths {
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                syn::NestedMeta::Lit(lit) => syn::ExprPath::from_value(lit),
+                syn::NestedMeta::Meta(meta) => {
+                    Err(darling::Error::unexpected_type("meta item").with_span(meta))
+                },
+            })
+            .collect::<darling::Result<_>>()
+            .map(Paths)
+    }
+}
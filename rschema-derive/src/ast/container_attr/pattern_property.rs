@@ -0,0 +1,10 @@
+use darling::FromMeta;
+
+// The type of the additional properties whose names match the pattern.
+// 
+// e.x.) `#[rschema(pattern_properties(pattern = "^x-", schema = "Extension"))]`
+#[derive(Debug, FromMeta)]
+pub struct PatternProperty {
+    pub pattern: String,
+    pub schema: syn::TypePath,
+}
@@ -1,7 +1,13 @@
 use darling::FromDeriveInput;
 
 use super::{
+    Bound,
     Case,
+    Dependent,
+    ExclusiveGroup,
+    Paths,
+    PatternProperty,
+    When,
     definitions::{
         Definitions,
         and_then,
@@ -14,10 +20,43 @@ pub struct StructAttr {
     #[darling(default)]
     pub additional_properties: Option<bool>,
 
+    #[darling(default)]
+    pub default: Option<bool>,
+
+    #[darling(default)]
+    pub read_only: Option<bool>,
+
+    #[darling(default)]
+    pub write_only: Option<bool>,
+
     #[darling(default)]
     pub rename_all: Option<Case>,
 
+    #[darling(default, multiple)]
+    pub when: Vec<When>,
+
+    #[darling(default, multiple)]
+    pub dependent: Vec<Dependent>,
+
+    #[darling(default, multiple)]
+    pub exclusive_group: Vec<ExclusiveGroup>,
+
+    #[darling(default, multiple)]
+    pub pattern_properties: Vec<PatternProperty>,
+
+    #[darling(default, multiple)]
+    pub example: Vec<syn::ExprPath>,
+
+    #[darling(default)]
+    pub examples: Paths,
+
+    #[darling(default, rename = "const")]
+    pub const_value: Option<syn::ExprPath>,
+
     #[darling(default)]
     #[darling(and_then = "and_then")]
     pub defs: Definitions,
+
+    #[darling(default)]
+    pub bound: Option<Bound>,
 }
@@ -1,6 +1,22 @@
 use darling::FromDeriveInput;
 
+use super::Paths;
+
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(rschema))]
 pub struct UnitStructAttr {
+    #[darling(default)]
+    pub read_only: Option<bool>,
+
+    #[darling(default)]
+    pub write_only: Option<bool>,
+
+    #[darling(default, multiple)]
+    pub example: Vec<syn::ExprPath>,
+
+    #[darling(default)]
+    pub examples: Paths,
+
+    #[darling(default, rename = "const")]
+    pub const_value: Option<syn::ExprPath>,
 }
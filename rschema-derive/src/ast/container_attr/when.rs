@@ -0,0 +1,138 @@
+use darling::{
+    FromMeta,
+    util::{
+        PathList,
+        SpannedValue,
+    },
+};
+use syn::{
+    Lit,
+    Meta,
+    NestedMeta,
+};
+
+use crate::Field;
+
+// A condition on the value of a field, which makes other fields required.
+// 
+// Allowed format are either
+//  - `#[rschema(when(field = "kind", equals = "s3", require(bucket)))]`
+//  - `#[rschema(when(kind = "s3", require(bucket)))]`
+// 
+// The latter is for repeating conditions on the same field. Clippy's `duplicated_attributes` lint
+// compares the nested items of an attribute, so two `field = "kind"` in one `#[rschema(...)]` are
+// reported on the user's code, where the derive cannot allow the lint.
+#[derive(Debug)]
+pub struct When {
+    pub field: SpannedValue<String>,
+    pub equals: String,
+    pub require: PathList,
+}
+
+impl FromMeta for When {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut field = None;
+        let mut equals = None;
+        let mut require = None;
+        let mut errors = vec![];
+
+        for item in items {
+            match item {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("field") => {
+                    match String::from_value(&nv.lit) {
+                        Ok(name) => field = Some(SpannedValue::new(name, nv.lit.span())),
+                        Err(e) => errors.push(e.with_span(&nv.lit)),
+                    }
+                },
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("equals") => {
+                    match String::from_value(&nv.lit) {
+                        Ok(value) => equals = Some(value),
+                        Err(e) => errors.push(e.with_span(&nv.lit)),
+                    }
+                },
+                NestedMeta::Meta(meta @ Meta::List(list)) if list.path.is_ident("require") => {
+                    match PathList::from_meta(meta) {
+                        Ok(paths) => require = Some(paths),
+                        Err(e) => errors.push(e),
+                    }
+                },
+                NestedMeta::Meta(Meta::NameValue(nv)) if field.is_none() && nv.path.get_ident().is_some() => {
+                    // The shorthand `kind = "s3"`.
+                    match (nv.path.get_ident(), &nv.lit) {
+                        (Some(ident), Lit::Str(value)) => {
+                            field = Some(SpannedValue::new(ident.to_string(), ident.span()));
+                            equals = Some(value.value());
+                        },
+                        (_, lit) => errors.push(darling::Error::unexpected_lit_type(lit)),
+                    }
+                },
+                NestedMeta::Meta(meta) => {
+                    errors.push(darling::Error::unknown_field_path(meta.path()).with_span(meta));
+                },
+                NestedMeta::Lit(lit) => {
+                    errors.push(darling::Error::unexpected_lit_type(lit));
+                },
+            }
+        }
+
+        if field.is_none() {
+            errors.push(darling::Error::missing_field("field"));
+        }
+        if equals.is_none() {
+            errors.push(darling::Error::missing_field("equals"));
+        }
+        if require.is_none() {
+            errors.push(darling::Error::missing_field("require"));
+        }
+
+        match (field, equals, require) {
+            (Some(field), Some(equals), Some(require)) if errors.is_empty() => Ok(When {
+                field,
+                equals,
+                require,
+            }),
+            _ => Err(darling::Error::multiple(errors)),
+        }
+    }
+}
+
+impl When {
+    // All of the names must be of the fields in the schema, so that they can be renamed.
+    pub fn check(
+        &self,
+        fields: &[Field],
+    ) -> darling::Result<()> {
+        let mut errors = vec![];
+
+        if find_field(fields, &self.field).is_none() {
+            let message = format!("Unknown field `{}` in `when`", *self.field);
+            errors.push(darling::Error::custom(message).with_span(&self.field.span()));
+        }
+
+        for path in self.require.iter() {
+            let exists = path
+                .get_ident()
+                .and_then(|ident| find_field(fields, &ident.to_string()))
+                .is_some();
+            if !exists {
+                let message = format!("Unknown field `{}` in `require`", quote::quote!(#path));
+                errors.push(darling::Error::custom(message).with_span(path));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(darling::Error::multiple(errors))
+        }
+    }
+}
+
+pub fn find_field<'a, 'b>(
+    fields: &'a [Field<'b>],
+    name: &str,
+) -> Option<&'a Field<'b>> {
+    fields
+        .iter()
+        .find(|field| matches!(field.ident, Some(ident) if ident == name))
+}
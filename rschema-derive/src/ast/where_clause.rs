@@ -0,0 +1,96 @@
+use proc_macro2::TokenStream;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+use crate::{
+    Bound,
+    Data,
+    DefaultValue,
+    Field,
+    StructAttribute,
+};
+
+use super::Container;
+
+// The where clause with the bounds required by the type parameters and the attributes.
+// 
+// Each type parameter is required to be `Schematic`, unless `bound` is given instead.
+// `Serialize` is required only for the types whose values are written, such as by `default`.
+pub struct WhereClause<'a>(pub &'a Container<'a>);
+
+impl<'a> ToTokens for WhereClause<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let container = self.0;
+        let mut generics = container.generics.clone();
+        let where_clause = generics.make_where_clause();
+
+        let attr = &container.attr;
+        match attr.bound {
+            Some(Bound(ref predicates)) => {
+                where_clause.predicates.extend(predicates.iter().cloned());
+            },
+            None => {
+                for param in container.generics.type_params() {
+                    let ident = &param.ident;
+                    where_clause.predicates.push(syn::parse_quote! {
+                        #ident: rschema::Schematic
+                    });
+                }
+            },
+        }
+
+        let fill_defaults = matches!(container.data, Data::Struct(_)) && attr.fill_defaults();
+        let has_values = !attr.example.is_empty()
+            || !attr.examples.0.is_empty()
+            || attr.const_value.is_some();
+        if let Some(bounds) = bounds(fill_defaults, has_values) {
+            where_clause.predicates.push(syn::parse_quote! {
+                Self: #bounds
+            });
+        }
+
+        for field in fields(&container.data) {
+            let Field { attr, source_ty, .. } = field;
+            let has_default_trait = attr.default == Some(DefaultValue::Trait);
+            let has_values = attr.default.is_some()
+                || !attr.examples.0.is_empty()
+                || !attr.example.is_empty()
+                || attr.const_value.is_some();
+            if let Some(bounds) = bounds(has_default_trait, has_values) {
+                where_clause.predicates.push(syn::parse_quote! {
+                    #source_ty: #bounds
+                });
+            }
+        }
+
+        // Nothing is written if there are no predicates.
+        where_clause.to_tokens(tokens);
+    }
+}
+
+fn bounds(
+    default: bool,
+    serialize: bool,
+) -> Option<TokenStream> {
+    match (default, serialize) {
+        (true, _) => Some(quote! { Default + rschema::__private::Serialize }),
+        (false, true) => Some(quote! { rschema::__private::Serialize }),
+        (false, false) => None,
+    }
+}
+
+// The fields written as properties or items.
+fn fields<'a>(data: &'a Data) -> Vec<&'a Field<'a>> {
+    match data {
+        Data::Struct(fields) | Data::TupleStruct(fields) => fields.iter().collect(),
+        Data::Enum(variants) => {
+            variants
+                .iter()
+                .flat_map(|variant| fields(&variant.data))
+                .collect()
+        },
+        Data::UnitStruct | Data::NewTypeStruct(_) => vec![],
+    }
+}
@@ -1,19 +1,31 @@
 use crate::{
     Case,
     Definitions,
+    Dependent,
+    ExclusiveGroup,
+    PatternProperty,
+    When,
 };
 
 pub trait ContainerAttribute {
     fn definitions(&self) -> &Definitions;
+    fn read_only(&self) -> bool;
+    fn write_only(&self) -> bool;
 }
 
 pub trait EnumAttribute {
+    fn one_of(&self) -> bool;
     fn rename_all(&self) -> Option<Case>;
 }
 
 pub trait StructAttribute {
     fn additional_properties(&self) -> bool;
+    fn fill_defaults(&self) -> bool;
     fn rename_all(&self) -> Option<Case>;
+    fn when(&self) -> &[When];
+    fn dependent(&self) -> &[Dependent];
+    fn exclusive_groups(&self) -> &[ExclusiveGroup];
+    fn pattern_properties(&self) -> &[PatternProperty];
 }
 
 pub trait TupleStructAttribute {
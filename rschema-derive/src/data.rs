@@ -12,7 +12,11 @@ mod variant;
 mod variant_attr;
 
 pub use field::Field;
-pub use field_attr::FieldAttr;
+pub use field_attr::{
+    DefaultValue,
+    FieldAttr,
+    PropertyNames,
+};
 pub use variant::Variant;
 pub use variant_attr::{
     OtherVariantAttr,
@@ -113,6 +117,7 @@ fn parse_field(
             attr,
             ident: field.ident.as_ref(),
             ty,
+            source_ty: &field.ty,
         }
     });
     Ok(field)
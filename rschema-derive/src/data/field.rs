@@ -1,4 +1,7 @@
-use crate::is_falsy;
+use crate::{
+    find_field,
+    is_falsy,
+};
 
 use super::FieldAttr;
 
@@ -7,10 +10,64 @@ pub struct Field<'a> {
     pub attr: FieldAttr,
     pub ident: Option<&'a syn::Ident>,
     pub ty: syn::Type,
+    // The type written in the source, even if `alt` is given.
+    pub source_ty: &'a syn::Type,
 }
 
 impl<'a> Field<'a> {
     pub fn required(&self) -> bool {
         !is_falsy(&self.attr.required)
     }
-}
+
+    // The names in `requires` must be of the fields in the schema, so that they can be renamed.
+    pub fn check_requires(
+        &self,
+        fields: &[Field],
+    ) -> darling::Result<()> {
+        let mut errors = vec![];
+
+        for path in self.attr.requires.iter() {
+            let exists = self.ident.is_some() && path
+                .get_ident()
+                .and_then(|ident| find_field(fields, &ident.to_string()))
+                .is_some();
+            if !exists {
+                let message = format!("Unknown field `{}` in `requires`", quote::quote!(#path));
+                errors.push(darling::Error::custom(message).with_span(path));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(darling::Error::multiple(errors))
+        }
+    }
+
+    // A group makes sense only with two or more named fields.
+    pub fn check_exclusive_group(
+        &self,
+        fields: &[Field],
+    ) -> darling::Result<()> {
+        let name = match self.attr.exclusive_group {
+            Some(ref name) => name,
+            None => return Ok(()),
+        };
+
+        if self.ident.is_none() {
+            let message = "`exclusive_group` is not allowed on unnamed fields";
+            return Err(darling::Error::custom(message).with_span(self.source_ty));
+        }
+
+        let len = fields
+            .iter()
+            .filter(|field| field.attr.exclusive_group.as_ref() == Some(name))
+            .count();
+        if len < 2 {
+            let message = format!("The exclusive group `{}` must have two or more fields", name);
+            return Err(darling::Error::custom(message).with_span(self.source_ty));
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,4 +1,17 @@
-use darling::FromField;
+use darling::{
+    FromField,
+    util::PathList,
+};
+
+mod default_value;
+mod literals;
+mod number;
+mod property_names;
+
+pub use default_value::DefaultValue;
+pub use literals::Literals;
+pub use number::Number;
+pub use property_names::PropertyNames;
 
 #[derive(Debug, FromField, PartialEq)]
 #[darling(attributes(rschema))]
@@ -13,7 +26,23 @@ pub struct FieldAttr {
     #[darling(default)]
     pub deprecated: Option<bool>,
     #[darling(default)]
+    pub read_only: Option<bool>,
+    #[darling(default)]
+    pub write_only: Option<bool>,
+    #[darling(default)]
     pub required: Option<bool>,
+    #[darling(default)]
+    pub requires: PathList,
+    #[darling(default)]
+    pub exclusive_group: Option<String>,
+    #[darling(default)]
+    pub default: Option<DefaultValue>,
+    #[darling(default)]
+    pub examples: Literals,
+    #[darling(default, multiple)]
+    pub example: Vec<syn::ExprPath>,
+    #[darling(default, rename = "const")]
+    pub const_value: Option<syn::Lit>,
 
     /* type: string */
     #[darling(default)]
@@ -24,18 +53,24 @@ pub struct FieldAttr {
     pub pattern: Option<String>,
     #[darling(default)]
     pub format: Option<String>,
+    #[darling(default)]
+    pub content_encoding: Option<String>,
+    #[darling(default)]
+    pub content_media_type: Option<String>,
+    #[darling(default)]
+    pub content_schema: Option<syn::TypePath>,
 
     /* type: number */
     #[darling(default)]
-    pub minimum: Option<i64>,
+    pub minimum: Option<Number>,
     #[darling(default)]
-    pub maximum: Option<i64>,
+    pub maximum: Option<Number>,
     #[darling(default)]
-    pub multiple_of: Option<i64>,
+    pub multiple_of: Option<Number>,
     #[darling(default)]
-    pub exclusive_minimum: Option<i64>,
+    pub exclusive_minimum: Option<Number>,
     #[darling(default)]
-    pub exclusive_maximum: Option<i64>,
+    pub exclusive_maximum: Option<Number>,
 
     /* type: array */
     #[darling(default)]
@@ -44,6 +79,24 @@ pub struct FieldAttr {
     pub max_items: Option<usize>,
     #[darling(default)]
     pub unique_items: Option<bool>,
+    #[darling(default)]
+    pub contains: Option<syn::TypePath>,
+    #[darling(default)]
+    pub min_contains: Option<usize>,
+    #[darling(default)]
+    pub max_contains: Option<usize>,
+
+    /* type: object */
+    #[darling(default)]
+    pub min_properties: Option<usize>,
+    #[darling(default)]
+    pub max_properties: Option<usize>,
+    #[darling(default)]
+    pub property_names: Option<PropertyNames>,
+
+    /* composition */
+    #[darling(default)]
+    pub not: Option<syn::TypePath>,
 
     /* control */
     #[darling(default)]
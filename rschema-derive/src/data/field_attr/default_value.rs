@@ -0,0 +1,24 @@
+use darling::FromMeta;
+
+// The value of the `default` keyword.
+// 
+// Allowed format are either
+//  - `#[rschema(default)]`: `Default::default()` of the field type
+//  - `#[rschema(default = "path::to::fn")]`: the value returned by the function
+#[derive(Debug, PartialEq)]
+pub enum DefaultValue {
+    Trait,
+    Path(syn::ExprPath),
+}
+
+impl FromMeta for DefaultValue {
+    fn from_word() -> darling::Result<Self> {
+        Ok(DefaultValue::Trait)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value)
+            .map(DefaultValue::Path)
+            .map_err(|_| darling::Error::unknown_value(value))
+    }
+}
@@ -0,0 +1,22 @@
+use darling::FromMeta;
+
+// Literals given in a list.
+// 
+// e.x.) `#[rschema(examples("a", "b"))]`
+#[derive(Debug, Default, PartialEq)]
+pub struct Literals(pub Vec<syn::Lit>);
+
+impl FromMeta for Literals {
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                syn::NestedMeta::Lit(lit) => Ok(lit.clone()),
+                syn::NestedMeta::Meta(meta) => {
+                    Err(darling::Error::unexpected_type("meta item").with_span(meta))
+                },
+            })
+            .collect::<darling::Result<_>>()
+            .map(Literals)
+    }
+}
@@ -0,0 +1,77 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+// A numeric value of keywords such as `minimum`.
+// 
+// Allowed format are either
+//  - `#[rschema(minimum = 1)]`
+//  - `#[rschema(minimum = 0.5)]`
+//  - `#[rschema(minimum = "-0.5")]`
+// 
+// The value is kept as written, so that an integer is not output as a decimal.
+#[derive(Debug, PartialEq)]
+pub enum Number {
+    PosInt(u64),
+    NegInt(i64),
+    Float(f64),
+}
+
+impl FromMeta for Number {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        if let Ok(n) = value.parse::<u64>() {
+            Ok(Number::PosInt(n))
+        } else if let Ok(n) = value.parse::<i64>() {
+            Ok(Number::NegInt(n))
+        } else {
+            match value.parse::<f64>() {
+                Ok(n) => float(n),
+                Err(_) => Err(darling::Error::unknown_value(value)),
+            }
+        }
+    }
+
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let number = match value {
+            syn::Lit::Int(lit) if lit.base10_digits().starts_with('-') => {
+                lit.base10_parse().map(Number::NegInt)?
+            },
+            syn::Lit::Int(lit) => {
+                lit.base10_parse().map(Number::PosInt)?
+            },
+            syn::Lit::Float(lit) => {
+                float(lit.base10_parse()?)?
+            },
+            syn::Lit::Str(lit) => {
+                Self::from_string(&lit.value())?
+            },
+            _ => return Err(darling::Error::unexpected_lit_type(value)),
+        };
+
+        Ok(number)
+    }
+}
+
+fn float(n: f64) -> darling::Result<Number> {
+    if n.is_finite() {
+        Ok(Number::Float(n))
+    } else {
+        Err(darling::Error::custom("The number must be finite"))
+    }
+}
+
+impl ToTokens for Number {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            Self::PosInt(n) => quote! { rschema::Number::from(#n) },
+            Self::NegInt(n) => quote! { rschema::Number::from(#n) },
+            Self::Float(n) => quote! {
+                // Never fails because the value is checked to be finite.
+                rschema::Number::from_f64(#n).unwrap()
+            },
+        });
+    }
+}
@@ -0,0 +1,16 @@
+use darling::FromMeta;
+
+// String keywords which every key of a map must match.
+// 
+// e.x.) `#[rschema(property_names(pattern = "^[a-z_]+$", max_length = 32))]`
+#[derive(Debug, Default, FromMeta, PartialEq)]
+pub struct PropertyNames {
+    #[darling(default)]
+    pub min_length: Option<u64>,
+    #[darling(default)]
+    pub max_length: Option<u64>,
+    #[darling(default)]
+    pub pattern: Option<String>,
+    #[darling(default)]
+    pub format: Option<String>,
+}
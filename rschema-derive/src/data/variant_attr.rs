@@ -4,8 +4,12 @@ use crate::{
     Case,
     ContainerAttribute,
     Definitions,
+    Dependent,
+    ExclusiveGroup,
+    PatternProperty,
     StructAttribute,
     TupleStructAttribute,
+    When,
     is_falsy,
 };
 
@@ -82,6 +86,14 @@ impl ContainerAttribute for VariantAttr {
     fn definitions(&self) -> &Definitions {
         &Definitions::Skip
     }
+
+    fn read_only(&self) -> bool {
+        false
+    }
+
+    fn write_only(&self) -> bool {
+        false
+    }
 }
 
 impl StructAttribute for VariantAttr {
@@ -89,9 +101,29 @@ impl StructAttribute for VariantAttr {
         !is_falsy(&self.additional_properties)
     }
 
+    fn fill_defaults(&self) -> bool {
+        false
+    }
+
     fn rename_all(&self) -> Option<Case> {
         self.rename_all
     }
+
+    fn when(&self) -> &[When] {
+        &[]
+    }
+
+    fn dependent(&self) -> &[Dependent] {
+        &[]
+    }
+
+    fn exclusive_groups(&self) -> &[ExclusiveGroup] {
+        &[]
+    }
+
+    fn pattern_properties(&self) -> &[PatternProperty] {
+        &[]
+    }
 }
 
 impl TupleStructAttribute for VariantAttr {
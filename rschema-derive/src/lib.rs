@@ -12,8 +12,15 @@ mod data;
 mod tokens;
 
 use ast::{
+    Bound,
     Container,
+    ContainerAttr,
     Definitions,
+    Dependent,
+    ExclusiveGroup,
+    PatternProperty,
+    When,
+    find_field,
 };
 use attribute::{
     ContainerAttribute,
@@ -24,7 +31,9 @@ use attribute::{
 use case::Case;
 use data::{
     Data,
+    DefaultValue,
     Field,
+    PropertyNames,
     Variant,
 };
 use tokens::*;
@@ -60,7 +69,7 @@ fn expand_derive_schematic(
 fn impl_body<'a>(container: &'a Container) -> ImplSchematicBody<'a> {
     let (
         fn_type_body,
-        fn_defs_map_body,
+        mut fn_defs_map_body,
     ) = match container.data {
         Data::Struct(ref fields) => {
             func_bodies_for_struct(&container.attr, fields)
@@ -79,12 +88,20 @@ fn impl_body<'a>(container: &'a Container) -> ImplSchematicBody<'a> {
         },
     };
 
-    let fn_type = FnType::new(fn_type_body);
+    // A type without `defs` is written inline, unless it reaches itself through its fields.
+    let inline = matches!(container.attr.defs, Definitions::Skip);
+    if inline {
+        fn_defs_map_body.set_recursive(&fn_type_body);
+    }
+
+    let fn_type = FnType::new(fn_type_body, inline);
     let fn_defs_map = FnDefsMap::new(fn_defs_map_body);
+    let fn_annotations = FnAnnotations::new(&container.attr);
 
     ImplSchematicBody {
         fn_type,
         fn_defs_map,
+        fn_annotations,
     }
 }
 
@@ -93,11 +110,13 @@ fn func_bodies_for_struct<'a>(
     fields: &'a [Field],
 ) -> FuncBodies<'a> {
     let mut fn_type_body = FnTypeBody::for_struct(attr, fields);
-    let fn_defs_map_body = FnDefsMapBody::with_fields(
+    let mut fn_defs_map_body = FnDefsMapBody::with_fields(
         attr,
         &mut fn_type_body,
         fields,
     );
+    fn_defs_map_body.append_types(attr.dependent().iter().map(|dependent| &dependent.schema));
+    fn_defs_map_body.append_types(attr.pattern_properties().iter().map(|pattern_property| &pattern_property.schema));
 
     (
         fn_type_body,
@@ -118,9 +137,10 @@ fn func_bodies_for_newtype_struct<'a>(
     field: &'a Field,
 ) -> FuncBodies<'a> {
     let mut fn_type_body = FnTypeBody::for_newtype(field);
-    let fn_defs_map_body = FnDefsMapBody::new(
+    let fn_defs_map_body = FnDefsMapBody::with_fields(
         attr,
         &mut fn_type_body,
+        std::slice::from_ref(field),
     );
 
     (
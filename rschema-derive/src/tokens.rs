@@ -6,16 +6,19 @@ use quote::{
 
 use crate::Container;
 
+mod fn_annotations;
 mod fn_defs_map;
 mod fn_type;
 pub(self) mod utils;
 
+pub use fn_annotations::*;
 pub use fn_defs_map::*;
 pub use fn_type::*;
 
 pub struct ImplSchematicBody<'a> {
     pub fn_type: FnType<'a>,
     pub fn_defs_map: FnDefsMap,
+    pub fn_annotations: FnAnnotations<'a>,
 }
 
 impl<'a> ToTokens for ImplSchematicBody<'a> {
@@ -23,11 +26,13 @@ impl<'a> ToTokens for ImplSchematicBody<'a> {
         let ImplSchematicBody {
             fn_type,
             fn_defs_map,
+            fn_annotations,
         } = self;
 
         tokens.extend(quote! {
             #fn_type
             #fn_defs_map
+            #fn_annotations
         })
     }
 }
@@ -0,0 +1,63 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+use crate::{
+    ContainerAttr,
+    ContainerAttribute,
+};
+
+// The values given to the root schema, from the container attributes.
+pub struct FnAnnotations<'a> {
+    attr: &'a ContainerAttr,
+}
+
+impl<'a> ToTokens for FnAnnotations<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ContainerAttr {
+            example,
+            examples,
+            const_value,
+            ..
+        } = self.attr;
+
+        // `readOnly` and `writeOnly` are also given to the properties of this type in other schemas.
+        let read_only = self.attr.read_only();
+        let write_only = self.attr.write_only();
+
+        let example: Vec<_> = examples.0.iter().chain(example).collect();
+        if example.is_empty() && const_value.is_none() && !read_only && !write_only {
+            return;
+        }
+
+        let const_value = match const_value {
+            Some(path) => quote! { rschema::__private::to_value::<Self>(&#path()) },
+            None => quote! { None },
+        };
+
+        tokens.extend(quote! {
+            fn __annotations(_: rschema::__private::Sealed) -> rschema::__private::Annotations {
+                let examples: Vec<Option<rschema::__private::Value>> = vec![
+                    #(
+                        rschema::__private::to_value::<Self>(&#example()),
+                    )*
+                ];
+
+                rschema::__private::Annotations::new(
+                    examples.into_iter().flatten().collect(),
+                    #const_value,
+                    #read_only,
+                    #write_only,
+                )
+            }
+        });
+    }
+}
+
+impl<'a> FnAnnotations<'a> {
+    pub fn new(attr: &'a ContainerAttr) -> Self {
+        Self { attr }
+    }
+}
@@ -13,18 +13,40 @@ use crate::{
 use super::FnTypeBody;
 
 pub struct FnDefsMapBody {
-    stmt_insert_self: Option<TokenStream2>,
+    // The name given by `defs`, or `None` if it is auto-generated, and the definition of the type itself.
+    insert_self: Option<(Option<String>, TokenStream2)>,
+    // The definition of the type itself without `defs`, which is given only if it reaches itself.
+    insert_recursive: Option<TokenStream2>,
     stmts: Vec<TokenStream2>,
 }
 
 impl ToTokens for FnDefsMapBody {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let ref stmt_insert_self = self.stmt_insert_self;
+        let stmt_insert_self = self.insert_self.as_ref().map(|(name, def)| match name {
+            Some(name) => quote! {
+                // Defined in `$defs` with the given name, followed by the generic arguments if any.
+                defs_map.insert::<Self>(
+                    rschema::__private::generic_name(#name, std::any::type_name::<Self>()),
+                    #def,
+                );
+            },
+            None => quote! {
+                // Defined in `$defs` with the name which the naming strategy of the schema generates.
+                defs_map.insert_auto::<Self>(#def);
+            },
+        });
+        let stmt_insert_recursive = self.insert_recursive.as_ref().map(|def| quote! {
+            // Defined in `$defs` only if `schema()` refers to it, which is when it reaches itself.
+            if let Some(def) = rschema::__private::recursive_definition::<Self>(|| #def) {
+                rschema::__private::insert_recursive::<Self>(&mut defs_map, def);
+            }
+        });
         let ref stmts = self.stmts;
 
         tokens.extend(quote! {
             let mut defs_map = rschema::DefinitionsMap::new();
             #stmt_insert_self
+            #stmt_insert_recursive
             #(
                 #stmts
             )*
@@ -38,35 +60,36 @@ impl FnDefsMapBody {
         attr: &impl ContainerAttribute,
         fn_type_body: &mut FnTypeBody,
     ) -> Self {
-        let stmt_insert_self = match attr.definitions() {
-            Definitions::Named(name) => {
-                // Defined in `$defs` with the given name.
-                let def_name = quote! { #name };
-                quote_stmt_insert_defs(def_name, fn_type_body)
-            },
-            Definitions::Auto => {
-                // Defined in `$defs` with the auto-generated name.
-                let def_name = quote! {
-                    std::any::type_name::<Self>()
-                };
-                quote_stmt_insert_defs(def_name, fn_type_body)
-            },
+        let insert_self = match attr.definitions() {
+            Definitions::Named(name) => Some((Some(name.clone()), take_def(fn_type_body))),
+            Definitions::Auto => Some((None, take_def(fn_type_body))),
             Definitions::Skip => None,
         };
 
         Self {
-            stmt_insert_self,
+            insert_self,
+            insert_recursive: None,
             stmts: vec![],
         }
     }
 
     pub fn empty() -> Self {
         Self {
-            stmt_insert_self: None,
+            insert_self: None,
+            insert_recursive: None,
             stmts: vec![],
         }
     }
 
+    // Defines the type itself in case it reaches itself, as `schema()` refers to it then.
+    // This is only for the type without `defs`, whose `schema()` is kept.
+    pub fn set_recursive(
+        &mut self,
+        fn_type_body: &FnTypeBody,
+    ) {
+        self.insert_recursive = Some(fn_type_body.to_token_stream());
+    }
+
     pub fn with_stmts(
         attr: &impl ContainerAttribute,
         fn_type_body: &mut FnTypeBody,
@@ -96,6 +119,16 @@ impl FnDefsMapBody {
         )
     }
 
+    // Takes in the definitions of the types used other than as the fields.
+    pub fn append_types<'a>(
+        &mut self,
+        tys: impl IntoIterator<Item = &'a syn::TypePath>,
+    ) {
+        self.stmts.extend(tys.into_iter().map(|ty| quote! {
+            defs_map.extend_ty::<#ty>();
+        }));
+    }
+
     pub fn with_defs_maps(
         attr: &impl ContainerAttribute,
         fn_type_body: &mut FnTypeBody,
@@ -112,21 +145,14 @@ impl FnDefsMapBody {
     }
 }
 
-fn quote_stmt_insert_defs(
-    def_name: TokenStream2,
-    fn_type_body: &mut FnTypeBody,
-) -> Option<TokenStream2> {
-    // Make `__type()` return `Type::Ref(def_name)`.
-    let new_fn_type_body = FnTypeBody::Ref(def_name.clone());
-    let def = std::mem::replace(fn_type_body, new_fn_type_body);
-
-    // Instead, the original return value of `__type()` is defined in `$defs`.
-    Some(quote! {
-        defs_map.insert::<Self>(
-            #def_name,
-            #def,
-        );
-    })
+fn take_def(fn_type_body: &mut FnTypeBody) -> TokenStream2 {
+    // Make `schema()` return a reference by the type name, which is rewritten into the name in `$defs` when it is built.
+    let new_fn_type_body = FnTypeBody::Ref(quote! {
+        std::any::type_name::<Self>()
+    });
+
+    // Instead, the original return value of `schema()` is defined in `$defs`.
+    std::mem::replace(fn_type_body, new_fn_type_body).to_token_stream()
 }
 
 pub struct FnDefsMap {
@@ -138,7 +164,7 @@ impl ToTokens for FnDefsMap {
         let ref body = self.body;
 
         tokens.extend(quote! {
-            fn __defs_map() -> rschema::DefinitionsMap {
+            fn definitions() -> rschema::DefinitionsMap {
                 #body
             }
         });
@@ -151,10 +177,23 @@ impl FnDefsMap {
     }
 }
 
-fn quote_stmt_append_defs(Field{ ty, .. }: &Field) -> TokenStream2 {
+fn quote_stmt_append_defs(Field{ attr, ty, .. }: &Field) -> TokenStream2 {
+    let stmt_append_not_defs = attr.not.as_ref().map(|not| quote! {
+        defs_map.extend_ty::<#not>();
+    });
+    let stmt_append_contains_defs = attr.contains.as_ref().map(|contains| quote! {
+        defs_map.extend_ty::<#contains>();
+    });
+    let stmt_append_content_schema_defs = attr.content_schema.as_ref().map(|content_schema| quote! {
+        defs_map.extend_ty::<#content_schema>();
+    });
+
     quote! {
         // このプロパティの型が持っている DefinitionsMap を取り込む。
         defs_map.extend_ty::<#ty>();
+        #stmt_append_not_defs
+        #stmt_append_contains_defs
+        #stmt_append_content_schema_defs
     }
 }
 
@@ -162,7 +201,7 @@ fn stmt_extend_defs_map(
     defs_map: &FnDefsMapBody,
 ) -> TokenStream2 {
     quote! {
-        defs_map.extend({
+        defs_map.append({
             #defs_map
         });
     }
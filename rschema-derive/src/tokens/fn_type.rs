@@ -21,13 +21,23 @@ use super::utils::{
 };
 
 mod additional_properties;
+mod conditionals;
+mod dependent_required;
+mod dependent_schemas;
+mod exclusive_groups;
 mod items;
+mod pattern_properties;
 mod properties;
 mod required;
 mod unique_items;
 
 pub use additional_properties::AdditionalProperties;
+pub use conditionals::Conditionals;
+pub use dependent_required::DependentRequired;
+pub use dependent_schemas::DependentSchemas;
+pub use exclusive_groups::ExclusiveGroups;
 pub use items::Items;
+pub use pattern_properties::PatternProperties;
 pub use properties::Properties;
 pub use required::Required;
 pub use unique_items::UniqueItems;
@@ -35,8 +45,13 @@ pub use unique_items::UniqueItems;
 pub enum FnTypeBody<'a> {
     Struct {
         properties: Properties<'a>,
+        pattern_properties: PatternProperties<'a>,
         required: Required<'a>,
         additional_properties: AdditionalProperties,
+        dependent_required: DependentRequired<'a>,
+        dependent_schemas: DependentSchemas<'a>,
+        conditionals: Conditionals<'a>,
+        exclusive_groups: ExclusiveGroups<'a>,
     },
 
     UnitStruct,
@@ -52,6 +67,7 @@ pub enum FnTypeBody<'a> {
     Enum {
         types: Vec<FnTypeBody<'a>>,
         enum_units_type: Option<TokenStream2>,
+        one_of: bool,
     },
 
     Ref(TokenStream2),
@@ -62,14 +78,28 @@ impl<'a> ToTokens for FnTypeBody<'a> {
         let token_stream = match self {
             Self::Struct {
                 properties,
+                pattern_properties,
                 required,
                 additional_properties,
+                dependent_required,
+                dependent_schemas,
+                conditionals,
+                exclusive_groups,
             } => quote! {
-                rschema::Type::Object(rschema::ObjectKeys {
+                rschema::Type::Object(Box::new(rschema::ObjectKeys {
                     properties: #properties,
+                    pattern_properties: #pattern_properties,
                     required: #required,
                     additional_properties: #additional_properties,
-                })
+                    property_names: None,
+                    min_properties: None,
+                    max_properties: None,
+                    dependent_required: #dependent_required,
+                    dependent_schemas: #dependent_schemas,
+                    conditionals: #conditionals,
+                    exclusive_groups: #exclusive_groups,
+                    other_subschemas: vec![],
+                }))
             },
 
             Self::UnitStruct => quote! {
@@ -84,18 +114,22 @@ impl<'a> ToTokens for FnTypeBody<'a> {
                 unique_items,
             } => {
                 quote! {
-                    rschema::Type::Array(rschema::ArrayKeys {
+                    rschema::Type::Array(Box::new(rschema::ArrayKeys {
                         items: #items,
                         min_items: Some(#items_len),
                         max_items: Some(#items_len),
                         unique_items: #unique_items,
-                    })
+                        contains: None,
+                        min_contains: None,
+                        max_contains: None,
+                    }))
                 }
             },
 
             Self::Enum {
                 types,
                 enum_units_type,
+                one_of,
             } => {
                 match (types.is_empty(), enum_units_type) {
                     ( true, None) => {
@@ -107,6 +141,18 @@ impl<'a> ToTokens for FnTypeBody<'a> {
                         // Only unit variants
                         quote! { #ty }
                     },
+                    _ if *one_of => {
+                        quote! {
+                            rschema::Type::OneOf(rschema::OneOfKeys {
+                                one_of: vec![
+                                    #(
+                                        #types,
+                                    )*
+                                    #enum_units_type // Don't put a comma at the end.
+                                ],
+                            })
+                        }
+                    },
                     _ => {
                         quote! {
                             rschema::Type::Enum(rschema::EnumKeys {
@@ -123,7 +169,7 @@ impl<'a> ToTokens for FnTypeBody<'a> {
             }
 
             Self::Ref(def_name) => quote! {
-                rschema::Type::Ref(#def_name)
+                rschema::Type::Ref(#def_name.into())
             },
         };
 
@@ -138,8 +184,13 @@ impl<'a> FnTypeBody<'a> {
     ) -> Self {
         Self::Struct {
             properties: Properties::new(attr, fields),
+            pattern_properties: PatternProperties::new(attr),
             required: Required::new(fields),
             additional_properties: AdditionalProperties::new(attr),
+            dependent_required: DependentRequired::new(attr, fields),
+            dependent_schemas: DependentSchemas::new(attr, fields),
+            conditionals: Conditionals::new(attr, fields),
+            exclusive_groups: ExclusiveGroups::new(attr, fields),
         }
     }
 
@@ -154,7 +205,7 @@ impl<'a> FnTypeBody<'a> {
         fields: &'a [Field],
     ) -> Self {
         Self::TupleStruct {
-            items: Items::new(fields),
+            items: Items::new(attr, fields),
             items_len: fields.len(),
             unique_items: UniqueItems::new(attr),
         }
@@ -170,33 +221,30 @@ impl<'a> FnTypeBody<'a> {
         Self::Enum {
             types,
             enum_units_type,
+            one_of: attr.one_of(),
         }
     }
 }
 
 pub struct FnType<'a> {
     body: FnTypeBody<'a>,
+    // Whether the type is written inline, which is referred to instead where it reaches itself.
+    inline: bool,
 }
 
 impl<'a> ToTokens for FnType<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let ref body = self.body;
+        let body = if self.inline {
+            quote! {
+                rschema::__private::inline_or_ref::<Self>(|| #body)
+            }
+        } else {
+            body.to_token_stream()
+        };
 
         tokens.extend(quote! {
-            fn __type(
-                min_length: Option<u64>,
-                max_length: Option<u64>,
-                pattern: Option<String>,
-                format: Option<String>,
-                minimum: Option<i64>,
-                maximum: Option<i64>,
-                multiple_of: Option<i64>,
-                exclusive_minimum: Option<i64>,
-                exclusive_maximum: Option<i64>,
-                min_items: Option<usize>,
-                max_items: Option<usize>,
-                unique_items: Option<bool>,
-            ) -> rschema::Type {
+            fn schema(_: rschema::Constraints) -> rschema::Type {
                 #body
             }
         });
@@ -204,8 +252,14 @@ impl<'a> ToTokens for FnType<'a> {
 }
 
 impl<'a> FnType<'a> {
-    pub fn new(body: FnTypeBody<'a>) -> Self {
-        Self { body }
+    pub fn new(
+        body: FnTypeBody<'a>,
+        inline: bool,
+    ) -> Self {
+        Self {
+            body,
+            inline,
+        }
     }
 }
 
@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+use crate::{
+    Case,
+    Field,
+    StructAttribute,
+    When,
+};
+
+use super::utils::property_name;
+
+pub struct Conditionals<'a> {
+    when: &'a [When],
+    fields: &'a [Field<'a>],
+    rename_all: Option<Case>,
+}
+
+impl<'a> ToTokens for Conditionals<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let conditionals: Vec<TokenStream2> = self.when
+            .iter()
+            .map(|when| self.quote_conditional(when))
+            .collect();
+
+        tokens.extend(quote! {
+            vec![
+                #(
+                    #conditionals,
+                )*
+            ]
+        });
+    }
+}
+
+impl<'a> Conditionals<'a> {
+    pub fn new(
+        attr: &'a impl StructAttribute,
+        fields: &'a [Field],
+    ) -> Self {
+        Self {
+            when: attr.when(),
+            fields,
+            rename_all: attr.rename_all(),
+        }
+    }
+
+    fn quote_conditional(&self, when: &When) -> TokenStream2 {
+        let field = self.property_name(&when.field);
+        let equals = &when.equals;
+        let require: Vec<String> = when.require
+            .to_strings()
+            .iter()
+            .map(|name| self.property_name(name))
+            .collect();
+
+        quote! {
+            rschema::Conditional {
+                r#if: rschema::ObjectSubschema {
+                    properties: {
+                        let mut properties = rschema::Properties::new();
+                        properties.insert(
+                            #field.into(),
+                            rschema::Property {
+                                title: None,
+                                description: None,
+                                comment: None,
+                                deprecated: None,
+                                read_only: None,
+                                write_only: None,
+                                default: None,
+                                examples: vec![],
+                                r#const: None,
+                                ty: rschema::Type::String(rschema::StringKeys {
+                                    enm: vec![#equals.into()],
+                                    ..Default::default()
+                                }),
+                                extra: Default::default(),
+                            },
+                        );
+                        properties
+                    },
+                    required: vec![#field.into()],
+                },
+                then: Some(rschema::ObjectSubschema {
+                    properties: rschema::Properties::new(),
+                    required: vec![
+                        #(
+                            #require.into(),
+                        )*
+                    ],
+                }),
+                r#else: None,
+            }
+        }
+    }
+
+    fn property_name(&self, name: &str) -> String {
+        property_name(self.fields, name, self.rename_all)
+    }
+}
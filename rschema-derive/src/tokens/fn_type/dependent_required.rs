@@ -0,0 +1,70 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+use crate::{
+    Case,
+    Field,
+    StructAttribute,
+};
+
+use super::utils::property_name;
+
+pub struct DependentRequired<'a> {
+    fields: &'a [Field<'a>],
+    rename_all: Option<Case>,
+}
+
+impl<'a> ToTokens for DependentRequired<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let entries: Vec<TokenStream2> = self.fields
+            .iter()
+            .filter(|field| !field.attr.requires.is_empty())
+            .map(|field| self.quote_entry(field))
+            .collect();
+
+        tokens.extend(quote! {
+            vec![
+                #(
+                    #entries,
+                )*
+            ].into_iter().collect()
+        });
+    }
+}
+
+impl<'a> DependentRequired<'a> {
+    pub fn new(
+        attr: &impl StructAttribute,
+        fields: &'a [Field],
+    ) -> Self {
+        Self {
+            fields,
+            rename_all: attr.rename_all(),
+        }
+    }
+
+    fn quote_entry(&self, field: &Field) -> TokenStream2 {
+        let ident = field.ident
+            .unwrap_or_else(|| unreachable!("Oh, that's a bug. `requires` is given to an unnamed field."));
+        let name = property_name(self.fields, &ident.to_string(), self.rename_all);
+        let requires: Vec<String> = field.attr.requires
+            .to_strings()
+            .iter()
+            .map(|name| property_name(self.fields, name, self.rename_all))
+            .collect();
+
+        quote! {
+            (
+                #name.into(),
+                vec![
+                    #(
+                        #requires.into(),
+                    )*
+                ],
+            )
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+use crate::{
+    Case,
+    Dependent,
+    Field,
+    StructAttribute,
+};
+
+use super::utils::property_name;
+
+pub struct DependentSchemas<'a> {
+    dependent: &'a [Dependent],
+    fields: &'a [Field<'a>],
+    rename_all: Option<Case>,
+}
+
+impl<'a> ToTokens for DependentSchemas<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let entries: Vec<TokenStream2> = self.dependent
+            .iter()
+            .map(|dependent| {
+                let name = property_name(self.fields, &dependent.field, self.rename_all);
+                let schema = &dependent.schema;
+                quote! {
+                    (
+                        #name.into(),
+                        <#schema as Schematic>::schema(Default::default()),
+                    )
+                }
+            })
+            .collect();
+
+        tokens.extend(quote! {
+            vec![
+                #(
+                    #entries,
+                )*
+            ].into_iter().collect()
+        });
+    }
+}
+
+impl<'a> DependentSchemas<'a> {
+    pub fn new(
+        attr: &'a impl StructAttribute,
+        fields: &'a [Field],
+    ) -> Self {
+        Self {
+            dependent: attr.dependent(),
+            fields,
+            rename_all: attr.rename_all(),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+use crate::{
+    Case,
+    ExclusiveGroup,
+    Field,
+    StructAttribute,
+};
+
+use super::utils::rename_ident;
+
+pub struct ExclusiveGroups<'a> {
+    fields: &'a [Field<'a>],
+    rename_all: Option<Case>,
+    groups: &'a [ExclusiveGroup],
+}
+
+impl<'a> ToTokens for ExclusiveGroups<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        // The groups are ordered by the first field of each.
+        let mut names: Vec<&String> = vec![];
+        for field in self.fields {
+            if let Some(ref name) = field.attr.exclusive_group {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        let groups: Vec<TokenStream2> = names
+            .into_iter()
+            .map(|name| self.quote_group(name))
+            .collect();
+
+        tokens.extend(quote! {
+            vec![
+                #(
+                    #groups,
+                )*
+            ]
+        });
+    }
+}
+
+impl<'a> ExclusiveGroups<'a> {
+    pub fn new(
+        attr: &'a impl StructAttribute,
+        fields: &'a [Field],
+    ) -> Self {
+        Self {
+            fields,
+            rename_all: attr.rename_all(),
+            groups: attr.exclusive_groups(),
+        }
+    }
+
+    fn quote_group(&self, name: &str) -> TokenStream2 {
+        let properties: Vec<String> = self.fields
+            .iter()
+            .filter(|field| field.attr.exclusive_group.as_deref() == Some(name))
+            .filter_map(|field| field.ident.map(|ident| (field, ident)))
+            .map(|(field, ident)| rename_ident(ident, field.attr.rename.as_ref(), self.rename_all))
+            .collect();
+        let required = self.groups
+            .iter()
+            .any(|group| *group.name == name && group.required());
+
+        quote! {
+            rschema::ExclusiveGroup {
+                properties: vec![
+                    #(
+                        #properties.into(),
+                    )*
+                ],
+                required: #required,
+            }
+        }
+    }
+}
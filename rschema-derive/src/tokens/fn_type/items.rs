@@ -4,23 +4,31 @@ use quote::{
     quote,
 };
 
-use crate::Field;
+use crate::{
+    ContainerAttribute,
+    Field,
+};
 
 use super::utils::{
+    Access,
     quote_option,
+    quote_const,
+    quote_default,
+    quote_examples,
     quote_option_str,
     quote_ty,
 };
 
 pub struct Items<'a> {
     fields: &'a [Field<'a>],
+    access: Access,
 }
 
 impl<'a> ToTokens for Items<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let properties: Vec<TokenStream2> = self.fields
             .iter()
-            .map(quote_property)
+            .map(|field| quote_property(field, self.access))
             .collect();
 
         tokens.extend(quote! {
@@ -34,18 +42,31 @@ impl<'a> ToTokens for Items<'a> {
 }
 
 impl<'a> Items<'a> {
-    pub fn new(fields: &'a [Field]) -> Self {
-        Self { fields }
+    pub fn new(
+        attr: &impl ContainerAttribute,
+        fields: &'a [Field],
+    ) -> Self {
+        Self {
+            fields,
+            access: Access::new(attr),
+        }
     }
 }
 
-fn quote_property<'a>(field: &'a Field) -> TokenStream2 {
+fn quote_property<'a>(
+    field: &'a Field,
+    access: Access,
+) -> TokenStream2 {
     let Field { attr, .. } = field;
 
     let title = quote_option_str(&attr.title);
     let description = quote_option_str(&attr.description);
     let comment = quote_option_str(&attr.comment);
     let deprecated = quote_option(&attr.deprecated);
+    let (read_only, write_only) = access.quote(field);
+    let default = quote_default(field);
+    let examples = quote_examples(field);
+    let const_value = quote_const(field);
     let ty = quote_ty(field);
 
     quote! {
@@ -54,7 +75,13 @@ fn quote_property<'a>(field: &'a Field) -> TokenStream2 {
             description: #description,
             comment: #comment,
             deprecated: #deprecated,
+            read_only: #read_only,
+            write_only: #write_only,
+            default: #default,
+            examples: #examples,
+            r#const: #const_value,
             ty: #ty,
+            extra: Default::default(),
         }
     }
 }
@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+use crate::{
+    PatternProperty,
+    StructAttribute,
+};
+
+pub struct PatternProperties<'a> {
+    pattern_properties: &'a [PatternProperty],
+}
+
+impl<'a> ToTokens for PatternProperties<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let entries: Vec<TokenStream2> = self.pattern_properties
+            .iter()
+            .map(|PatternProperty { pattern, schema }| {
+                quote! {
+                    (
+                        #pattern.into(),
+                        <#schema as Schematic>::schema(Default::default()),
+                    )
+                }
+            })
+            .collect();
+
+        tokens.extend(quote! {
+            vec![
+                #(
+                    #entries,
+                )*
+            ].into_iter().collect()
+        });
+    }
+}
+
+impl<'a> PatternProperties<'a> {
+    pub fn new(attr: &'a impl StructAttribute) -> Self {
+        Self {
+            pattern_properties: attr.pattern_properties(),
+        }
+    }
+}
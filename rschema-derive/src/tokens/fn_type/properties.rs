@@ -6,12 +6,17 @@ use quote::{
 
 use crate::{
     Case,
+    ContainerAttribute,
     Field,
     StructAttribute,
 };
 
 use super::utils::{
+    Access,
     quote_option,
+    quote_const,
+    quote_default,
+    quote_examples,
     quote_option_str,
     quote_ty,
     rename_ident,
@@ -20,21 +25,32 @@ use super::utils::{
 pub struct Properties<'a> {
     fields: &'a [Field<'a>],
     rename_all: Option<Case>,
+    fill_defaults: bool,
+    access: Access,
 }
 
 impl<'a> ToTokens for Properties<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let stmts: Vec<TokenStream2> = self.fields
             .iter()
-            .map(|field| stmt_insert_property(field, self.rename_all))
+            .map(|field| stmt_insert_property(field, self.rename_all, self.access))
             .collect();
 
+        // `Self::default()` is serialized once for all the fields.
+        let fill_defaults = self.fill_defaults.then(|| quote! {
+            rschema::__private::fill_defaults(
+                &mut properties,
+                &<Self as Default>::default(),
+            );
+        });
+
         tokens.extend(quote! {
             {
                 let mut properties = rschema::Properties::new();
                 #(
                     #stmts
                 )*
+                #fill_defaults
                 properties
             }
         });
@@ -43,12 +59,14 @@ impl<'a> ToTokens for Properties<'a> {
 
 impl<'a> Properties<'a> {
     pub fn new(
-        attr: &impl StructAttribute,
+        attr: &(impl ContainerAttribute + StructAttribute),
         fields: &'a [Field],
     ) -> Self {
         Self {
             fields,
             rename_all: attr.rename_all(),
+            fill_defaults: attr.fill_defaults(),
+            access: Access::new(attr),
         }
     }
 }
@@ -56,6 +74,7 @@ impl<'a> Properties<'a> {
 fn stmt_insert_property<'a>(
     field: &'a Field,
     rename_all: Option<Case>,
+    access: Access,
 ) -> TokenStream2 {
     let (attr, ident) = if let Field {
         attr,
@@ -79,6 +98,10 @@ fn stmt_insert_property<'a>(
     let description = quote_option_str(&attr.description);
     let comment = quote_option_str(&attr.comment);
     let deprecated = quote_option(&attr.deprecated);
+    let (read_only, write_only) = access.quote(field);
+    let default = quote_default(field);
+    let examples = quote_examples(field);
+    let const_value = quote_const(field);
     let ty = quote_ty(field);
 
     quote! {
@@ -89,7 +112,13 @@ fn stmt_insert_property<'a>(
                 description: #description,
                 comment: #comment,
                 deprecated: #deprecated,
+                read_only: #read_only,
+                write_only: #write_only,
+                default: #default,
+                examples: #examples,
+                r#const: #const_value,
                 ty: #ty,
+                extra: Default::default(),
             },
         );
     }
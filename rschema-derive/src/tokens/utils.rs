@@ -8,9 +8,47 @@ use quote::{
 
 use crate::{
     Case,
+    ContainerAttribute,
+    DefaultValue,
     Field,
+    PropertyNames,
+    find_field,
 };
 
+// `readOnly` and `writeOnly` given to the container. They apply to all the fields.
+#[derive(Clone, Copy)]
+pub struct Access {
+    read_only: bool,
+    write_only: bool,
+}
+
+impl Access {
+    pub fn new(attr: &impl ContainerAttribute) -> Self {
+        Self {
+            read_only: attr.read_only(),
+            write_only: attr.write_only(),
+        }
+    }
+
+    // The attributes of the field take precedence, such as `#[rschema(read_only = false)]`.
+    // Otherwise, the flags given to the container of the field type apply.
+    pub fn quote(
+        &self,
+        field: &Field,
+    ) -> (TokenStream2, TokenStream2) {
+        let ty = &field.ty;
+        let read_only = match field.attr.read_only.or_else(|| self.read_only.then_some(true)) {
+            Some(read_only) => quote! { Some(#read_only) },
+            None => quote! { rschema::__private::read_only::<#ty>() },
+        };
+        let write_only = match field.attr.write_only.or_else(|| self.write_only.then_some(true)) {
+            Some(write_only) => quote! { Some(#write_only) },
+            None => quote! { rschema::__private::write_only::<#ty>() },
+        };
+        (read_only, write_only)
+    }
+}
+
 pub fn rename_ident(
     ident: &proc_macro2::Ident,
     rename: Option<&String>,
@@ -27,6 +65,24 @@ pub fn rename_ident(
     }
 }
 
+// The name of the field in the schema. The names are checked in advance.
+pub fn property_name(
+    fields: &[Field],
+    name: &str,
+    rename_all: Option<Case>,
+) -> String {
+    let field = find_field(fields, name)
+        .unwrap_or_else(|| unreachable!("Oh, that's a bug. `{}` is not a field.", name));
+    let ident = field.ident
+        .unwrap_or_else(|| unreachable!("Oh, that's a bug. Trying to rename an unnamed field."));
+
+    rename_ident(
+        ident,
+        field.attr.rename.as_ref(),
+        rename_all,
+    )
+}
+
 pub fn quote_option_str(val: &Option<String>) -> TokenStream2 {
     match val {
         Some(v) => quote! { Some(#v.into()) },
@@ -41,39 +97,164 @@ pub fn quote_option(val: &Option<impl ToTokens>) -> TokenStream2 {
     }
 }
 
+pub fn quote_default(field: &Field) -> TokenStream2 {
+    let Field { attr, source_ty, .. } = field;
+
+    match attr.default {
+        Some(DefaultValue::Trait) => quote_typed_value(source_ty, quote! { Default::default() }),
+        Some(DefaultValue::Path(ref path)) => quote_typed_value(source_ty, quote! { #path() }),
+        None => quote! { None },
+    }
+}
+
+// The value is typed as `ty`, so that a mismatch fails to compile.
+pub fn quote_typed_value(
+    ty: &syn::Type,
+    value: TokenStream2,
+) -> TokenStream2 {
+    quote! {
+        {
+            let value: #ty = #value;
+            rschema::__private::to_value(&value)
+        }
+    }
+}
+
+fn quote_lit(lit: &syn::Lit) -> TokenStream2 {
+    match lit {
+        // For `String` and `&str`.
+        syn::Lit::Str(_) => quote! { #lit.into() },
+        _ => quote! { #lit },
+    }
+}
+
+pub fn quote_examples(field: &Field) -> TokenStream2 {
+    let Field { attr, source_ty, .. } = field;
+
+    let literals = attr.examples.0
+        .iter()
+        .map(|lit| quote_typed_value(source_ty, quote_lit(lit)));
+    let paths = attr.example
+        .iter()
+        .map(|path| quote_typed_value(source_ty, quote! { #path() }));
+    let examples: Vec<TokenStream2> = literals.chain(paths).collect();
+
+    if examples.is_empty() {
+        return quote! { vec![] };
+    }
+
+    quote! {
+        vec![
+            #(
+                #examples,
+            )*
+        ].into_iter().flatten().collect()
+    }
+}
+
+pub fn quote_const(field: &Field) -> TokenStream2 {
+    let Field { attr, source_ty, .. } = field;
+
+    match attr.const_value {
+        Some(ref lit) => quote_typed_value(source_ty, quote_lit(lit)),
+        None => quote! { None },
+    }
+}
+
 pub fn quote_ty(
     field: &Field,
 ) -> TokenStream2 {
     let Field { attr, ty, .. } = field;
 
-    // params for each types
-    let min_length = quote_option(&attr.min_length);
-    let max_length = quote_option(&attr.max_length);
-    let format = quote_option_str(&attr.format);
-    let pattern = quote_option_str(&attr.pattern);
-    let minimum = quote_option(&attr.minimum);
-    let maximum = quote_option(&attr.maximum);
-    let multiple_of = quote_option(&attr.multiple_of);
-    let exclusive_minimum = quote_option(&attr.exclusive_minimum);
-    let exclusive_maximum = quote_option(&attr.exclusive_maximum);
-    let min_items = quote_option(&attr.min_items);
-    let max_items = quote_option(&attr.max_items);
-    let unique_items = quote_option(&attr.unique_items);
+    // The keywords for each types. Only the given ones are set.
+    let contains = attr.contains.as_ref().map(|contains| quote! {
+        <#contains as Schematic>::schema(Default::default())
+    });
+    let content_schema = attr.content_schema.as_ref().map(|content_schema| quote! {
+        <#content_schema as Schematic>::schema(Default::default())
+    });
+    let constraints = [
+        ("min_length", attr.min_length.as_ref().map(ToTokens::to_token_stream)),
+        ("max_length", attr.max_length.as_ref().map(ToTokens::to_token_stream)),
+        ("pattern", attr.pattern.as_ref().map(|pattern| quote! { #pattern.into() })),
+        ("format", attr.format.as_ref().map(|format| quote! { #format.into() })),
+        ("minimum", attr.minimum.as_ref().map(ToTokens::to_token_stream)),
+        ("maximum", attr.maximum.as_ref().map(ToTokens::to_token_stream)),
+        ("multiple_of", attr.multiple_of.as_ref().map(ToTokens::to_token_stream)),
+        ("exclusive_minimum", attr.exclusive_minimum.as_ref().map(ToTokens::to_token_stream)),
+        ("exclusive_maximum", attr.exclusive_maximum.as_ref().map(ToTokens::to_token_stream)),
+        ("min_items", attr.min_items.as_ref().map(ToTokens::to_token_stream)),
+        ("max_items", attr.max_items.as_ref().map(ToTokens::to_token_stream)),
+        ("unique_items", attr.unique_items.as_ref().map(ToTokens::to_token_stream)),
+        ("min_properties", attr.min_properties.as_ref().map(ToTokens::to_token_stream)),
+        ("max_properties", attr.max_properties.as_ref().map(ToTokens::to_token_stream)),
+        ("property_names", attr.property_names.as_ref().map(quote_property_names)),
+        ("contains", contains),
+        ("min_contains", attr.min_contains.as_ref().map(ToTokens::to_token_stream)),
+        ("max_contains", attr.max_contains.as_ref().map(ToTokens::to_token_stream)),
+        ("content_encoding", attr.content_encoding.as_ref().map(|encoding| quote! { #encoding.into() })),
+        ("content_media_type", attr.content_media_type.as_ref().map(|media_type| quote! { #media_type.into() })),
+        ("content_schema", content_schema),
+    ];
+    let stmts = constraints
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (format_ident!("{}", name), value)))
+        .map(|(name, value)| quote! {
+            constraints.#name = Some(#value);
+        })
+        .collect::<Vec<_>>();
+
+    let ty = if stmts.is_empty() {
+        quote! {
+            <#ty as Schematic>::schema(Default::default())
+        }
+    } else {
+        quote! {
+            <#ty as Schematic>::schema({
+                let mut constraints = rschema::Constraints::default();
+                #(
+                    #stmts
+                )*
+                constraints
+            })
+        }
+    };
+
+    match attr.not {
+        Some(ref not) => quote! {
+            rschema::Type::AllOf(rschema::AllOfKeys {
+                all_of: vec![
+                    #ty,
+                    rschema::Type::Not(rschema::NotKeys {
+                        not: Box::new(<#not as Schematic>::schema(Default::default())),
+                    }),
+                ],
+            })
+        },
+        None => ty,
+    }
+}
+
+fn quote_property_names(property_names: &PropertyNames) -> TokenStream2 {
+    let PropertyNames {
+        min_length,
+        max_length,
+        pattern,
+        format,
+    } = property_names;
+
+    let min_length = quote_option(min_length);
+    let max_length = quote_option(max_length);
+    let pattern = quote_option_str(pattern);
+    let format = quote_option_str(format);
 
     quote! {
-        <#ty as Schematic>::__type(
-            #min_length,
-            #max_length,
-            #pattern,
-            #format,
-            #minimum,
-            #maximum,
-            #multiple_of,
-            #exclusive_minimum,
-            #exclusive_maximum,
-            #min_items,
-            #max_items,
-            #unique_items,
-        )
+        rschema::Type::String(rschema::StringKeys {
+            min_length: #min_length,
+            max_length: #max_length,
+            pattern: #pattern,
+            format: #format,
+            ..Default::default()
+        })
     }
 }
@@ -102,6 +102,39 @@
 //! 
 //!   Indicates whether to allow properties not included in `properties`.
 //! 
+//! - `#[rschema(default)]`
+//! 
+//!   Give `default` to every field of a struct, from the fields of `Self::default()` serialized by *serde*.
+//!   The value is serialized only once, and picked by the property names, so keep the names the same as *serde*'s ones.
+//!   Fields with their own `default` attribute keep it.
+//! 
+//!   This requires the struct to implement `Default` and `serde::Serialize`.
+//! 
+//! - `#[rschema(example = "path::to::fn")]`
+//! 
+//!   Add the value returned by the given function to `examples` of the root schema. This can be given multiple times.
+//!   The function must return `Self`, and `Self` must implement `serde::Serialize`.
+//! 
+//! - `#[rschema(examples("path::to::fn1", "path::to::fn2"))]`
+//! 
+//!   Give `examples` of the root schema with the values returned by the given functions, as `example` does.
+//! 
+//! - `#[rschema(const = "path::to::fn")]`
+//! 
+//!   Give `const` of the root schema with the value returned by the given function, which must return `Self`.
+//! 
+//!   **Note**: Unlike `examples` and `const` of fields, which take literals, the ones of containers take functions,
+//!   since a value of `Self` cannot be written as a literal.
+//! 
+//! - `#[rschema(read_only)]`, `#[rschema(write_only)]`
+//! 
+//!   Give `readOnly` or `writeOnly` to every field of a struct or a tuple struct,
+//!   and to the fields of other structs whose type is this one, including through `Option` and `Box`.
+//!   Fields can opt out with `#[rschema(read_only = false)]` or `#[rschema(write_only = false)]`.
+//! 
+//!   These can also be given to an enum, a newtype struct and a unit struct.
+//!   For them, only the fields whose type is this one are given the flags, and the variants or the inner type are not.
+//! 
 //! - `#[rschema(rename_all = "...")]`
 //! 
 //!   Rename all the fields of structs or **unit** variants of enums according to the given case convention.
@@ -127,19 +160,63 @@
 //! 
 //!   Indicates that the tuple struct has unique values.
 //! 
+//! - `#[rschema(when(field = "kind", equals = "value", require(field1, field2)))]`
+//! 
+//!   Make the given fields of a struct required when the field `kind` equals the string `"value"`.
+//!   This generates `if` and `then`, and can be given multiple times. They are combined by `allOf`.
+//! 
+//!   The fields are given with their names in Rust, and checked at compile time. They are written with `rename` and `rename_all` applied.
+//! 
+//!   This can also be written as `when(kind = "value", require(...))`.
+//!   Use this form to repeat conditions on the same field in one `#[rschema(...)]`,
+//!   because Clippy's `duplicated_attributes` lint reports `field = "kind"` written twice in it.
+//! 
+//! - `#[rschema(dependent(field = "tls_cert", schema = "path"))]`
+//! 
+//!   Make the object match the schema of the given type when the field `tls_cert` is present. This generates `dependentSchemas`, and can be given multiple times for different fields.
+//!   The type is usually a struct with `#[rschema(additional_properties)]`, since the schema applies to the whole object.
+//! 
+//!   The field is checked at compile time as `when`. Before draft 2019-09, this is written in `dependencies`.
+//! 
+//! - `#[rschema(exclusive_group(name = "auth", required))]`
+//! 
+//!   Make the fields in the group `auth` required so that exactly one of them must be present. This generates `oneOf` instead of `not`.
+//!   See `exclusive_group` of the field attributes.
+//! 
+//! - `#[rschema(pattern_properties(pattern = "^x-", schema = "path"))]`
+//! 
+//!   Allow the properties whose names match the regular expression, with the schema of the given type. This generates `patternProperties`, and can be given multiple times.
+//!   The matched properties are allowed even without `additional_properties`.
+//! 
+//! - `#[rschema(one_of)]`
+//! 
+//!   Generate the variants of an enum in `oneOf` instead of `anyOf`, so that a value must match exactly one of them.
+//! 
 //! - `#[rschema(defs)]`
 //! 
 //!   Define in `$defs` with an auto-generated name.
 //! 
-//!   **Note**: This uses `std::any::type_name()` to generate as unique a name as possible.
+//!   **Note**: By default, this uses `std::any::type_name()` to generate as unique a name as possible.
 //!   However, it is not guaranteed to be unique. 
+//!   Give a [`Naming`] to [`Schema::naming`] to use shorter names, such as the identifier of the type.
 //! 
 //! - `#[rschema(defs = "name")]`
 //! 
 //!   Define in `$defs` with the given name.
+//!   For a generic type, the generic arguments follow the name, such as `Page_User` of `Page<User>`.
+//!   The lifetimes are not the arguments, so `View<'a>` is defined once for any lifetime.
+//! 
+//!   **Note**: Any name can be given, but different types with the same name cannot be written by default.
+//!   Give [`Collision::Suffix`] to [`Schema::on_collision`] to number the later ones instead, such as `Config_2`.
+//! 
+//! - `#[rschema(bound = "T: Schematic + Default")]`
 //! 
-//!   **Note**: Any name can be given, but if a name is duplicated, the definition is overwritten.
-//!   Therefore, it is recommended that do not use this for types that are published.
+//!   Use the given where predicates instead of the bounds generated for the type parameters.
+//!   By default, each type parameter must be `Schematic`. The type parameters do not have to be `'static`, such as `Page<View<'a>>`.
+//! 
+//! **Note**: A type which reaches itself through its fields, such as `children: Vec<Node>`, cannot be written inline.
+//! It is referred to where it is reached again, and defined in `$defs` with an auto-generated name, as if `#[rschema(defs)]` were given.
+//! This also applies to types that refer to each other, such as `A` having `Vec<B>` and `B` having `Option<Box<A>>`.
 //! 
 //! 
 //! ## Variant attributes
@@ -191,14 +268,61 @@
 //! 
 //!   Indicate that the property this keyword applies to should not be used and may be removed in the future.
 //! 
+//! - `#[rschema(read_only)]`
+//! 
+//!   Indicate that the property is managed by the owner, such as an ID assigned by a server, and should not be sent in a request.
+//! 
+//! - `#[rschema(write_only)]`
+//! 
+//!   Indicate that the property is never returned in a response, such as a password.
+//! 
 //! - `#[rschema(required)]`
 //! 
 //!   Indicate that the property this keyword applies to is required.
 //! 
+//! - `#[rschema(default)]`
+//! 
+//!   Give `default` with `Default::default()` of the field type. This requires the type to implement `Default` and `serde::Serialize`.
+//! 
+//! - `#[rschema(default = "path::to::fn")]`
+//! 
+//!   Give `default` with the value returned by the given function. The function must return the field type.
+//! 
+//! - `#[rschema(examples("a", "b"))]`
+//! 
+//!   Give `examples` with the given literals. Each literal is checked against the field type at compile time,
+//!   so give the values of the field type, such as strings for `String` and integers for `u32`.
+//! 
+//! - `#[rschema(example = "path::to::fn")]`
+//! 
+//!   Add the value returned by the given function to `examples`. This can be given multiple times, and used together with `examples`.
+//!   The function must return the field type. Use this for values that cannot be written as literals, such as `Option` and `Vec`.
+//! 
+//! - `#[rschema(const = 1)]`
+//! 
+//!   Give `const` with the given literal, which is checked against the field type as `examples`.
+//! 
+//!   The field type must implement `serde::Serialize` to use `default`, `examples`, `example` or `const`.
+//! 
+//! - `#[rschema(requires(tls_key, ...))]`
+//! 
+//!   Indicate that the given fields are required when this property is present. This generates `dependentRequired`.
+//! 
+//!   The fields are given with their names in Rust, and checked at compile time. Before draft 2019-09, this is written in `dependencies`.
+//! 
+//! - `#[rschema(exclusive_group = "auth")]`
+//! 
+//!   Indicate that at most one of the fields in the group `auth` may be present. This generates `not` with `anyOf` of each pair of them.
+//!   A group needs two or more fields, and several groups are combined by `allOf` with the conditions of `when`.
+//! 
 //! - `#[rschema(rename = "name")]`
 //! 
 //!   Renames the field name with the given name.
 //! 
+//! - `#[rschema(not = "path")]`
+//! 
+//!   Indicate that the value must not match the schema of the given type. The field type is combined with it by `allOf`.
+//! 
 //! - `#[rschema(alt = "path")]`
 //! 
 //!   This is very similar to the serde's `remote` attribute. But it does not check that all the fields in the definition you provided match those in the external type.
@@ -206,6 +330,7 @@
 //!   If use external types, it probably does not implement `Schematic`. In such a case, you need to give the schema information instead by specifying another type that implements `Schematic`.
 //! 
 //!   **Note**: Rschema does not pre-implement the `Schematic` for types provided by non-standard crates. It is to prevent cyclic package dependency, in the case that external crates come to implement `Schematic` in the future.
+//!   A crate can also implement `Schematic` for its own types manually. See [`Schematic`] for how to do it.
 //! 
 //! - `#[rschema(skip)]`
 //! 
@@ -230,9 +355,30 @@
 //! 
 //!   The basic semantic identification of certain kinds of string values that are commonly used.
 //! 
+//! - `#[rschema(content_encoding = "base64")]`
+//! 
+//!   Indicate how the content is encoded in the string.
+//! 
+//! - `#[rschema(content_media_type = "application/json")]`
+//! 
+//!   Indicate the media type of the content.
+//! 
+//! - `#[rschema(content_schema = "path")]`
+//! 
+//!   Describe the decoded content with the schema of the given type. Its definitions are merged into `$defs` of the root schema.
+//! 
+//!   These three keywords are annotations, and do not restrict the string in validation.
+//!   Writing a schema with them fails before the draft which introduced them, which is draft 7 for `contentEncoding`
+//!   and `contentMediaType`, and draft 2019-09 for `contentSchema`.
+//! 
 //! 
 //! #### `number`
 //! 
+//! Integer types generate an `integer` type property, and floating-point types generate a `number` type property.
+//! For integer types, `minimum` and `maximum` are given from the range of the type, unless they are specified explicitly.
+//! 
+//! The values of these keywords can be either integers or decimals, like `maximum = 0.95`. They are output as written.
+//! 
 //! - `#[rschema(minimum = 1)]`
 //! 
 //!   Specify the minimum of the range.
@@ -268,6 +414,58 @@
 //! 
 //!   Indicates that the array has unique values.
 //! 
+//! - `#[rschema(contains = "path")]`
+//! 
+//!   Indicate that the array must have an item matching the schema of the given type.
+//! 
+//! - `#[rschema(min_contains = 1)]`, `#[rschema(max_contains = 1)]`
+//! 
+//!   Specify how many items must match `contains`. They are ignored without `contains`, and the minimum is 1 if not given.
+//! 
+//! 
+//! #### `object`
+//! 
+//! These are for map types such as `HashMap<String, V>`.
+//! 
+//! - `#[rschema(min_properties = 1)]`
+//! 
+//!   Specify the minimum number of the entries. Give an integer greater than or equal to 0.
+//! 
+//! - `#[rschema(max_properties = 1)]`
+//! 
+//!   Specify the maximum number of the entries. Give an integer greater than or equal to 0.
+//! 
+//! - `#[rschema(property_names(pattern = "^[a-z_]+$"))]`
+//! 
+//!   Restrict the keys with `propertyNames`. This takes `min_length`, `max_length`, `pattern` and `format` as string fields do.
+//! 
+//! 
+//! # Schemas built at runtime
+//! 
+//! A schema can also be built without the derive macro, such as from the data given at runtime.
+//! 
+//! ```
+//! use rschema::{
+//!     DefinitionsMap,
+//!     Schema,
+//!     Type,
+//! };
+//! 
+//! let ty = Type::object()
+//!     .property("name", Type::string().min_length(1))
+//!     .required("name")
+//!     .deny_additional();
+//! let schema_str = Schema::from_type("Plugin", ty, DefinitionsMap::new()).to_string().unwrap();
+//! 
+//! assert_eq!(
+//!     schema_str,
+//!     r#"{"title":"Plugin","type":"object","properties":{"name":{"type":"string","minLength":1}},"required":["name"],"additionalProperties":false}"#
+//! );
+//! ```
+//! 
+//! The types deriving `Schematic` can be used together, by taking in their definitions with [`DefinitionsMap::extend_ty`].
+//! A definition built at runtime is added by [`Schema::define`].
+//! 
 //! 
 //! # Combination with Serde
 //! 
@@ -280,23 +478,48 @@
 
 pub use rschema_core::{
     AdditionalProperties,
+    AllOfKeys,
+    ArrayBuilder,
     ArrayKeys,
+    Change,
+    Collision,
+    Compatibility,
+    Conditional,
+    Constraints,
     Definitions,
     DefinitionsMap,
+    Diff,
     Draft,
     EnumKeys,
     Error,
+    ExclusiveGroup,
     Items,
+    Keywords,
+    Naming,
+    NotKeys,
+    Number,
+    NumericBuilder,
+    NumericKeys,
+    ObjectBuilder,
     ObjectKeys,
+    ObjectSubschema,
+    OneOfKeys,
+    Origin,
     Properties,
     Property,
     Result,
     Schema,
     Schematic,
+    StringBuilder,
     StringKeys,
     TupleKeys,
     Type,
+    ValidationError,
+    diff,
+    diff_types,
 };
+#[doc(hidden)]
+pub use rschema_core::__private;
 
 #[allow(unused_imports)]
 #[macro_use]
@@ -9,6 +9,8 @@ use rschema::{
 #[rschema(unique_items)]
 struct TupleStruct(u32, u32);
 
+// The variants are named after the kinds of variants under test.
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Schematic)]
 enum Enum {
     UnitVariant,
@@ -69,7 +71,9 @@ fn it_tests_array_properties() -> rschema::Result<()> {
       "title": "[i32; 3]",
       "type": "array",
       "items": {
-        "type": "number"
+        "type": "integer",
+        "minimum": -2147483648,
+        "maximum": 2147483647
       },
       "minItems": 3,
       "maxItems": 3
@@ -78,14 +82,18 @@ fn it_tests_array_properties() -> rschema::Result<()> {
       "title": "&[i32]",
       "type": "array",
       "items": {
-        "type": "number"
+        "type": "integer",
+        "minimum": -2147483648,
+        "maximum": 2147483647
       }
     },
     "prop_vec": {
       "title": "Vec<i32>",
       "type": "array",
       "items": {
-        "type": "number"
+        "type": "integer",
+        "minimum": -2147483648,
+        "maximum": 2147483647
       }
     },
     "prop_tuple": {
@@ -93,7 +101,9 @@ fn it_tests_array_properties() -> rschema::Result<()> {
       "type": "array",
       "items": [
         {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         {
           "type": "string"
@@ -102,6 +112,7 @@ fn it_tests_array_properties() -> rschema::Result<()> {
           "type": "boolean"
         }
       ],
+      "additionalItems": false,
       "minItems": 3,
       "maxItems": 3
     },
@@ -110,12 +121,17 @@ fn it_tests_array_properties() -> rschema::Result<()> {
       "type": "array",
       "items": [
         {
-          "type": "number"
+          "type": "integer",
+          "minimum": 0,
+          "maximum": 4294967295
         },
         {
-          "type": "number"
+          "type": "integer",
+          "minimum": 0,
+          "maximum": 4294967295
         }
       ],
+      "additionalItems": false,
       "minItems": 2,
       "maxItems": 2,
       "uniqueItems": true
@@ -127,12 +143,13 @@ fn it_tests_array_properties() -> rschema::Result<()> {
         "anyOf": [
           {
             "type": "array",
-            "items": [],
             "minItems": 0,
             "maxItems": 0
           },
           {
-            "type": "number"
+            "type": "integer",
+            "minimum": -2147483648,
+            "maximum": 2147483647
           },
           {
             "type": "array",
@@ -144,6 +161,7 @@ fn it_tests_array_properties() -> rschema::Result<()> {
                 "type": "boolean"
               }
             ],
+            "additionalItems": false,
             "minItems": 2,
             "maxItems": 2
           },
@@ -152,7 +170,9 @@ fn it_tests_array_properties() -> rschema::Result<()> {
             "properties": {
               "value": {
                 "title": "i32",
-                "type": "number"
+                "type": "integer",
+                "minimum": -2147483648,
+                "maximum": 2147483647
               }
             },
             "additionalProperties": false
@@ -170,7 +190,9 @@ fn it_tests_array_properties() -> rschema::Result<()> {
       "title": "All keywords",
       "type": "array",
       "items": {
-        "type": "number"
+        "type": "integer",
+        "minimum": -2147483648,
+        "maximum": 2147483647
       },
       "minItems": 1,
       "maxItems": 5,
@@ -44,13 +44,17 @@ fn it_tests_rename_attribute() -> rschema::Result<()> {
   "type": "object",
   "properties": {
     "prop_value_renamed": {
-      "type": "number"
+      "type": "integer",
+      "minimum": -2147483648,
+      "maximum": 2147483647
     },
     "prop_struct": {
       "type": "object",
       "properties": {
         "propValue1": {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         "propValue2": {
           "type": "string"
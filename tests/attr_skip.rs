@@ -24,7 +24,9 @@ fn it_tests_skip_attribute() -> rschema::Result<()> {
   "properties": {
     "prop_value": {
       "title": "value",
-      "type": "number"
+      "type": "integer",
+      "minimum": -2147483648,
+      "maximum": 2147483647
     }
   },
   "additionalProperties": false
@@ -0,0 +1,105 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "View")]
+struct View<'a> {
+    name: &'a str,
+
+    tags: Vec<&'a str>,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(defs)]
+struct Labeled<'a, T> {
+    label: &'a str,
+
+    value: T,
+}
+
+#[derive(Debug, Schematic)]
+struct Document<'a, 'b> {
+    main: View<'a>,
+
+    related: Vec<View<'b>>,
+
+    scores: Vec<Labeled<'a, i32>>,
+
+    flags: Option<Labeled<'b, bool>>,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Page")]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(bound = "T: Schematic")]
+struct Tree<T> {
+    value: T,
+
+    children: Vec<Tree<T>>,
+}
+
+// `View<'a>` is not `'static`, since `'a` is any lifetime here.
+fn borrowed_schemas<'a>(_view: &View<'a>) -> (Schema, Schema) {
+    (
+        Schema::new::<Page<View<'a>>>("Page"),
+        Schema::new::<Tree<View<'a>>>("Tree"),
+    )
+}
+
+#[test]
+fn it_defines_borrowed_types_once() {
+    let schema = Schema::new::<Document>("Document");
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string().unwrap()).unwrap();
+
+    assert_eq!(value["properties"]["main"]["$ref"], "#/$defs/View");
+    assert_eq!(value["properties"]["related"]["items"]["$ref"], "#/$defs/View");
+    assert_eq!(
+        value["$defs"]["View"]["properties"]["tags"],
+        json!({ "type": "array", "items": { "type": "string" } }),
+    );
+    assert_eq!(schema.defs().len(), 3);
+}
+
+#[test]
+fn it_defines_each_instantiation_of_borrowed_types() {
+    let schema = Schema::new::<Document>("Document");
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string().unwrap()).unwrap();
+
+    let scores = value["properties"]["scores"]["items"]["$ref"].as_str().unwrap();
+    let flags = value["properties"]["flags"]["anyOf"][0]["$ref"].as_str().unwrap();
+    assert_ne!(scores, flags);
+
+    let scores = &value["$defs"][scores.trim_start_matches("#/$defs/")];
+    let flags = &value["$defs"][flags.trim_start_matches("#/$defs/")];
+    assert_eq!(scores["properties"]["value"]["type"], "integer");
+    assert_eq!(flags["properties"]["value"]["type"], "boolean");
+}
+
+#[test]
+fn it_instantiates_generic_types_with_borrowed_types() {
+    let name = String::from("local");
+    let view = View {
+        name: &name,
+        tags: vec![],
+    };
+    let (page, tree) = borrowed_schemas(&view);
+
+    let page: serde_json::Value = serde_json::from_str(&page.to_string().unwrap()).unwrap();
+    assert_eq!(page["$ref"], "#/$defs/Page_View");
+    assert_eq!(page["$defs"]["Page_View"]["properties"]["items"]["items"]["$ref"], "#/$defs/View");
+
+    let tree: serde_json::Value = serde_json::from_str(&tree.to_string().unwrap()).unwrap();
+    let tree_ref = tree["$ref"].as_str().unwrap();
+    let tree_def = &tree["$defs"][tree_ref.trim_start_matches("#/$defs/")];
+    assert_eq!(tree_def["properties"]["value"]["$ref"], "#/$defs/View");
+    assert_eq!(tree_def["properties"]["children"]["items"]["$ref"], tree_ref);
+}
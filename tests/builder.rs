@@ -0,0 +1,175 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Constraints,
+    DefinitionsMap,
+    Naming,
+    Property,
+    Schema,
+    Schematic,
+    Type,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "User")]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(defs)]
+struct Group {
+    owner: User,
+}
+
+#[derive(Debug, Schematic)]
+struct Plugin {
+    #[rschema(
+        title = "Name",
+        min_length = 1,
+        required,
+    )]
+    name: String,
+
+    #[rschema(minimum = 1, maximum = 10)]
+    priority: u8,
+
+    #[rschema(unique_items)]
+    tags: Vec<String>,
+}
+
+fn plugin_type() -> Type {
+    Type::object()
+        .property("name", Property::new(Type::string().min_length(1)).title("Name"))
+        .property("priority", Type::integer().minimum(1).maximum(10))
+        .property("tags", Type::array(Type::string()).unique_items())
+        .required("name")
+        .deny_additional()
+        .build()
+}
+
+#[test]
+fn it_builds_the_same_schema_as_derive() -> rschema::Result<()> {
+    let built = Schema::from_type("Plugin", plugin_type(), DefinitionsMap::new())
+        .to_string_pretty()?;
+    let derived = Schema::new::<Plugin>("Plugin")
+        .to_string_pretty()?;
+
+    assert_eq!(built, derived);
+
+    Ok(())
+}
+
+#[test]
+fn it_builds_schemas_with_definitions() -> rschema::Result<()> {
+    let mut schema = Schema::from_type(
+        "Manifest",
+        Type::object()
+            .property("plugins", Type::array(Type::Ref("Plugin".into())).min_items(1))
+            .property("version", Type::string().pattern(r"^\d+\.\d+$"))
+            .pattern_property("^x-", Type::Boolean)
+            .required("plugins"),
+        DefinitionsMap::new(),
+    );
+    schema.define("Plugin", plugin_type());
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string()?)?;
+
+    assert_eq!(value["properties"]["plugins"]["items"]["$ref"], "#/$defs/Plugin");
+    assert_eq!(value["patternProperties"], json!({ "^x-": { "type": "boolean" } }));
+    assert_eq!(value["additionalProperties"], json!(true));
+    assert_eq!(value["$defs"]["Plugin"]["required"], json!(["name"]));
+
+    assert!(schema.validate(&json!({ "plugins": [{ "name": "lint" }], "x-beta": true })).is_ok());
+    assert!(schema.validate(&json!({ "plugins": [{ "name": "" }] })).is_err());
+    assert!(schema.validate(&json!({ "plugins": [] })).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn it_builds_schemas_with_derived_types() -> rschema::Result<()> {
+    let mut defs_map = DefinitionsMap::new();
+    defs_map.extend_ty::<User>();
+    defs_map.extend_ty::<Group>();
+
+    let mut schema = Schema::from_type(
+        "Team",
+        Type::object()
+            .property("leader", User::schema(Constraints::default()))
+            .property("groups", Type::array(Group::schema(Constraints::default())))
+            .property("plugins", Type::array(Type::Ref("Plugin".into()))),
+        defs_map,
+    );
+    schema.define("Plugin", plugin_type());
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string()?)?;
+
+    assert_eq!(value["properties"]["leader"]["$ref"], "#/$defs/User");
+    assert_eq!(value["properties"]["groups"]["items"]["$ref"], "#/$defs/builder::Group");
+    assert_eq!(value["properties"]["plugins"]["items"]["$ref"], "#/$defs/Plugin");
+    assert_eq!(value["$defs"]["builder::Group"]["properties"]["owner"]["$ref"], "#/$defs/User");
+    assert_eq!(value["$defs"]["Plugin"]["required"], json!(["name"]));
+
+    schema.naming(Naming::Ident);
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string()?)?;
+
+    assert_eq!(value["properties"]["groups"]["items"]["$ref"], "#/$defs/Group");
+    assert_eq!(value["$defs"].as_object().unwrap().keys().collect::<Vec<_>>(), ["User", "Group", "Plugin"]);
+
+    assert!(schema.validate(&json!({ "leader": { "name": "a" }, "groups": [{ "owner": { "name": "b" } }] })).is_ok());
+    assert!(schema.validate(&json!({ "groups": [{ "owner": {} }] })).is_ok());
+    assert!(schema.validate(&json!({ "groups": [{ "owner": { "name": 1 } }] })).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn it_renames_derived_definitions_among_defined_ones() -> rschema::Result<()> {
+    let mut defs_map = DefinitionsMap::new();
+    defs_map.extend_ty::<Group>();
+
+    let mut schema = Schema::from_type(
+        "Team",
+        Type::object()
+            .property("group", Group::schema(Constraints::default()))
+            .property("plugin", Type::Ref("Plugin".into())),
+        defs_map,
+    );
+    schema
+        .define("Plugin", Type::object().property("group", Type::Ref("builder::Group".into())))
+        .define("User", Type::string().build())
+        .naming(Naming::Ident);
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string()?)?;
+
+    assert_eq!(value["properties"]["group"]["$ref"], "#/$defs/Group");
+    assert_eq!(value["$defs"]["Plugin"]["properties"]["group"]["$ref"], "#/$defs/Group");
+    assert_eq!(value["$defs"]["Group"]["properties"]["owner"]["$ref"], "#/$defs/User");
+    assert_eq!(value["$defs"]["User"]["type"], "string");
+    assert_eq!(value["$defs"].as_object().unwrap().keys().collect::<Vec<_>>(), ["Group", "User", "Plugin"]);
+
+    Ok(())
+}
+
+#[test]
+fn it_builds_numeric_and_string_keywords() {
+    let value = serde_json::to_value(
+        Type::object()
+            .property("ratio", Type::number().exclusive_minimum(0).maximum(1))
+            .property("mode", Type::string().enum_values(["fast", "safe"]))
+            .property("payload", Type::string().content_encoding("base64").content_media_type("application/json"))
+            .build(),
+    ).unwrap();
+
+    assert_eq!(
+        value["properties"],
+        json!({
+            "ratio": { "type": "number", "maximum": 1, "exclusiveMinimum": 0 },
+            "mode": { "type": "string", "enum": ["fast", "safe"] },
+            "payload": {
+                "type": "string",
+                "contentEncoding": "base64",
+                "contentMediaType": "application/json",
+            },
+        }),
+    );
+}
@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+struct Reserved;
+
+#[derive(Debug, Schematic)]
+#[rschema(one_of)]
+enum Shape {
+    Circle {
+        #[rschema(required)]
+        radius: f64,
+    },
+    Square {
+        #[rschema(required)]
+        side: f64,
+    },
+    Point,
+}
+
+#[derive(Debug, Schematic)]
+struct Composition {
+    prop_shape: Shape,
+
+    #[rschema(not = "Reserved")]
+    prop_not_null: Option<String>,
+}
+
+#[test]
+fn it_tests_composition() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Composition>("Composition")
+        .to_string_pretty()?;
+    let schema_str2 = r#"{
+  "title": "Composition",
+  "type": "object",
+  "properties": {
+    "prop_shape": {
+      "oneOf": [
+        {
+          "type": "object",
+          "properties": {
+            "radius": {
+              "type": "number"
+            }
+          },
+          "required": [
+            "radius"
+          ],
+          "additionalProperties": false
+        },
+        {
+          "type": "object",
+          "properties": {
+            "side": {
+              "type": "number"
+            }
+          },
+          "required": [
+            "side"
+          ],
+          "additionalProperties": false
+        },
+        {
+          "type": "string",
+          "enum": [
+            "Point"
+          ]
+        }
+      ]
+    },
+    "prop_not_null": {
+      "allOf": [
+        {
+          "anyOf": [
+            {
+              "type": "string"
+            },
+            {
+              "type": "null"
+            }
+          ]
+        },
+        {
+          "not": {
+            "type": "null"
+          }
+        }
+      ]
+    }
+  },
+  "additionalProperties": false
+}"#;
+
+    assert_eq!(schema_str, schema_str2);
+
+    let schema: Schema = schema_str.parse()?;
+    assert_eq!(schema.to_string_pretty()?, schema_str2);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_composition_validation() {
+    let schema = Schema::new::<Composition>("Composition");
+
+    assert!(schema.validate(&json!({ "prop_shape": { "side": 1.0 }, "prop_not_null": "a" })).is_ok());
+
+    let errors = schema.validate(&json!({ "prop_shape": { "side": 1.0, "radius": 1.0 }, "prop_not_null": null }))
+        .unwrap_err();
+    let paths: Vec<_> = errors
+        .iter()
+        .map(|error| (error.instance_path.as_str(), error.schema_path.as_str()))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            ("/prop_shape", "/properties/prop_shape/oneOf"),
+            ("/prop_not_null", "/properties/prop_not_null/allOf/1/not"),
+        ],
+    );
+}
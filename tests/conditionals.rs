@@ -0,0 +1,138 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(
+    rename_all = "camelCase",
+    when(field = "kind", equals = "s3", require(bucket_name)),
+)]
+struct Single {
+    #[rschema(required)]
+    kind: String,
+
+    bucket_name: Option<String>,
+}
+
+// With `field = "kind"` in both conditions, `cargo clippy` fails on `clippy::duplicated_attributes`.
+#[derive(Debug, Schematic)]
+#[rschema(
+    when(kind = "s3", require(bucket, region)),
+    when(kind = "local", require(local_path)),
+)]
+struct Storage {
+    #[rschema(required)]
+    kind: String,
+
+    bucket: Option<String>,
+
+    region: Option<String>,
+
+    #[rschema(rename = "path")]
+    local_path: Option<String>,
+}
+
+#[test]
+fn it_tests_single_condition() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Single>("Single")
+        .to_string_pretty()?;
+    let schema_str2 = r#"{
+  "title": "Single",
+  "type": "object",
+  "properties": {
+    "kind": {
+      "type": "string"
+    },
+    "bucketName": {
+      "anyOf": [
+        {
+          "type": "string"
+        },
+        {
+          "type": "null"
+        }
+      ]
+    }
+  },
+  "required": [
+    "kind"
+  ],
+  "additionalProperties": false,
+  "if": {
+    "properties": {
+      "kind": {
+        "type": "string",
+        "enum": [
+          "s3"
+        ]
+      }
+    },
+    "required": [
+      "kind"
+    ]
+  },
+  "then": {
+    "required": [
+      "bucketName"
+    ]
+  }
+}"#;
+
+    assert_eq!(schema_str, schema_str2);
+
+    let schema: Schema = schema_str.parse()?;
+    assert_eq!(schema.to_string_pretty()?, schema_str2);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_multiple_conditions() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Storage>("Storage")
+        .to_string_pretty()?;
+    let schema_value: serde_json::Value = serde_json::from_str(&schema_str)?;
+
+    assert_eq!(
+        schema_value["allOf"],
+        json!([
+            {
+                "if": {
+                    "properties": { "kind": { "type": "string", "enum": ["s3"] } },
+                    "required": ["kind"],
+                },
+                "then": { "required": ["bucket", "region"] },
+            },
+            {
+                "if": {
+                    "properties": { "kind": { "type": "string", "enum": ["local"] } },
+                    "required": ["kind"],
+                },
+                "then": { "required": ["path"] },
+            },
+        ]),
+    );
+
+    let schema: Schema = schema_str.parse()?;
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_conditions_validation() {
+    let schema = Schema::new::<Storage>("Storage");
+
+    assert!(schema.validate(&json!({ "kind": "s3", "bucket": "b", "region": "r" })).is_ok());
+    assert!(schema.validate(&json!({ "kind": "local", "path": "/tmp" })).is_ok());
+    assert!(schema.validate(&json!({ "kind": "memory" })).is_ok());
+
+    let errors = schema.validate(&json!({ "kind": "local", "bucket": "b" }))
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].schema_path, "/allOf/1/then/required");
+    assert_eq!(errors[0].message, "does not have the required property `path`");
+}
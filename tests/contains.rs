@@ -0,0 +1,87 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Draft,
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(additional_properties)]
+struct Listener {
+    #[rschema(required)]
+    port: u16,
+
+    primary: Option<bool>,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(
+    additional_properties,
+    defs = "PrimaryListener",
+)]
+struct PrimaryListener {
+    #[rschema(required, const = true)]
+    primary: bool,
+}
+
+#[derive(Debug, Schematic)]
+struct Server {
+    #[rschema(contains = "PrimaryListener", max_contains = 1)]
+    listeners: Vec<Listener>,
+
+    #[rschema(contains = "u16", min_contains = 2)]
+    ports: Vec<u16>,
+}
+
+#[test]
+fn it_tests_contains() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Server>("Server"))?;
+
+    assert_eq!(schema["properties"]["listeners"]["contains"], json!({
+        "$ref": "#/$defs/PrimaryListener",
+    }));
+    assert_eq!(schema["properties"]["listeners"]["maxContains"], json!(1));
+    assert!(schema["properties"]["listeners"].get("minContains").is_none());
+    assert_eq!(schema["properties"]["ports"]["minContains"], json!(2));
+    assert_eq!(schema["$defs"]["PrimaryListener"]["properties"]["primary"]["const"], json!(true));
+
+    Ok(())
+}
+
+#[test]
+fn it_validates_contains() {
+    let schema = Schema::new::<Server>("Server");
+
+    let primary = json!({ "port": 80, "primary": true });
+    let secondary = json!({ "port": 81 });
+
+    assert!(schema.validate(&json!({ "listeners": [secondary, primary], "ports": [1, 2] })).is_ok());
+
+    let errors = schema
+        .validate(&json!({ "listeners": [secondary], "ports": [1] }))
+        .unwrap_err();
+    let schema_paths: Vec<&str> = errors
+        .iter()
+        .map(|error| error.schema_path.as_str())
+        .collect();
+
+    assert_eq!(schema_paths, [
+        "/properties/listeners/contains",
+        "/properties/ports/minContains",
+    ]);
+
+    let errors = schema
+        .validate(&json!({ "listeners": [primary, primary], "ports": [1, 2] }))
+        .unwrap_err();
+
+    assert_eq!(errors[0].schema_path, "/properties/listeners/maxContains");
+}
+
+#[test]
+fn it_does_not_write_min_contains_before_2019_09() {
+    let mut schema = Schema::new::<Server>("Server");
+
+    assert!(schema.schema(Draft::Draft7).to_string().is_err());
+}
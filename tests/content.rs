@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Draft,
+    Error,
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Manifest")]
+struct Manifest {
+    #[rschema(required)]
+    version: String,
+}
+
+#[derive(Debug, Schematic)]
+struct Thumbnail {
+    #[rschema(content_encoding = "base64", content_media_type = "image/png")]
+    data: String,
+}
+
+#[derive(Debug, Schematic)]
+struct Upload {
+    #[rschema(content_encoding = "base64", content_media_type = "image/png")]
+    thumbnail: String,
+
+    #[rschema(
+        content_media_type = "application/json",
+        content_schema = "Manifest",
+    )]
+    manifest: String,
+}
+
+#[test]
+fn it_tests_content() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Upload>("Upload"))?;
+
+    assert_eq!(schema["properties"]["thumbnail"], json!({
+        "type": "string",
+        "contentEncoding": "base64",
+        "contentMediaType": "image/png",
+    }));
+    assert_eq!(schema["properties"]["manifest"], json!({
+        "type": "string",
+        "contentMediaType": "application/json",
+        "contentSchema": { "$ref": "#/$defs/Manifest" },
+    }));
+    assert_eq!(schema["$defs"]["Manifest"]["required"], json!(["version"]));
+
+    Ok(())
+}
+
+#[test]
+fn it_does_not_validate_content() {
+    let schema = Schema::new::<Upload>("Upload");
+
+    assert!(schema.validate(&json!({ "thumbnail": "not base64", "manifest": "{}" })).is_ok());
+}
+
+#[test]
+fn it_reads_content() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Upload>("Upload").to_string()?;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string()?, schema_str);
+
+    Ok(())
+}
+
+#[test]
+fn it_does_not_write_content_before_its_draft() {
+    let unsupported = |result: rschema::Result<String>| match result {
+        Err(Error::UnsupportedKeywordError { keyword, .. }) => keyword,
+        result => panic!("unexpected result: {:?}", result),
+    };
+
+    let mut schema = Schema::new::<Upload>("Upload");
+    assert_eq!(unsupported(schema.schema(Draft::Draft7).to_string()), "contentSchema");
+
+    let mut schema = Schema::new::<Thumbnail>("Thumbnail");
+    assert!(schema.schema(Draft::Draft7).to_string().is_ok());
+    assert_eq!(unsupported(schema.schema(Draft::Draft6).to_string()), "contentEncoding");
+    assert_eq!(unsupported(schema.schema(Draft::Draft4).to_string()), "contentEncoding");
+}
@@ -0,0 +1,140 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde::Serialize;
+use serde_json::json;
+
+fn default_host() -> String {
+    "localhost".into()
+}
+
+#[derive(Debug, Schematic)]
+struct Fields {
+    #[rschema(default)]
+    retries: u32,
+
+    #[rschema(default)]
+    token: Option<String>,
+
+    #[rschema(default = "default_host")]
+    host: String,
+
+    timeout: u32,
+}
+
+#[derive(Debug, Serialize, Schematic)]
+#[serde(rename_all = "camelCase")]
+#[rschema(
+    default,
+    rename_all = "camelCase",
+)]
+struct Container {
+    max_connections: u32,
+
+    log_level: String,
+
+    #[rschema(default = "default_host")]
+    host: String,
+
+    #[serde(skip)]
+    skipped: bool,
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Container {
+            max_connections: 16,
+            log_level: "info".into(),
+            host: "0.0.0.0".into(),
+            skipped: false,
+        }
+    }
+}
+
+#[derive(Debug, Schematic)]
+struct Generic<T: Schematic> {
+    #[rschema(default)]
+    value: T,
+}
+
+#[derive(Debug, Schematic)]
+struct Tuple(
+    #[rschema(default)]
+    bool,
+
+    #[rschema(default = "default_host")]
+    String,
+);
+
+#[test]
+fn it_tests_field_defaults() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Fields>("Fields"))?;
+    let properties = &schema["properties"];
+
+    assert_eq!(properties["retries"]["default"], json!(0));
+    assert_eq!(properties["token"]["default"], json!(null));
+    assert_eq!(properties["host"]["default"], json!("localhost"));
+    assert!(properties["timeout"].get("default").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_container_default() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Container>("Container")
+        .to_string_pretty()?;
+    let schema_str2 = r#"{
+  "title": "Container",
+  "type": "object",
+  "properties": {
+    "maxConnections": {
+      "default": 16,
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 4294967295
+    },
+    "logLevel": {
+      "default": "info",
+      "type": "string"
+    },
+    "host": {
+      "default": "localhost",
+      "type": "string"
+    },
+    "skipped": {
+      "type": "boolean"
+    }
+  },
+  "additionalProperties": false
+}"#;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_generic_and_tuple_defaults() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Generic<Vec<u8>>>("Generic"))?;
+    assert_eq!(schema["properties"]["value"]["default"], json!([]));
+
+    let schema = serde_json::to_value(Schema::new::<Tuple>("Tuple"))?;
+    assert_eq!(schema["items"][0]["default"], json!(false));
+    assert_eq!(schema["items"][1]["default"], json!("localhost"));
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_null_default_round_trip() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Fields>("Fields")
+        .to_string_pretty()?;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
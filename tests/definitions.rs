@@ -37,6 +37,8 @@ struct Struct {
     prop_nested_struct: NestedStruct,
 }
 
+// The variants are named after the kinds of variants under test.
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Schematic)]
 #[rschema(defs)]
 enum Enum {
@@ -95,7 +97,9 @@ fn it_tests_definitions() -> rschema::Result<()> {
       "type": "object",
       "properties": {
         "prop_value": {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         "prop_nested_struct": {
           "$ref": "#/$defs/definitions::NestedStruct"
@@ -107,7 +111,9 @@ fn it_tests_definitions() -> rschema::Result<()> {
       "type": "object",
       "properties": {
         "prop_value": {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         }
       },
       "additionalProperties": false
@@ -116,12 +122,13 @@ fn it_tests_definitions() -> rschema::Result<()> {
       "anyOf": [
         {
           "type": "array",
-          "items": [],
           "minItems": 0,
           "maxItems": 0
         },
         {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         {
           "type": "array",
@@ -133,6 +140,7 @@ fn it_tests_definitions() -> rschema::Result<()> {
               "type": "boolean"
             }
           ],
+          "additionalItems": false,
           "minItems": 2,
           "maxItems": 2
         },
@@ -140,7 +148,9 @@ fn it_tests_definitions() -> rschema::Result<()> {
           "type": "object",
           "properties": {
             "value": {
-              "type": "number"
+              "type": "integer",
+              "minimum": -2147483648,
+              "maximum": 2147483647
             }
           },
           "additionalProperties": false
@@ -151,7 +161,9 @@ fn it_tests_definitions() -> rschema::Result<()> {
       "type": "object",
       "properties": {
         "prop_value": {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         "prop_private": {
           "$ref": "#/$defs/definitions::external_crate::PrivateStruct"
@@ -163,7 +175,9 @@ fn it_tests_definitions() -> rschema::Result<()> {
       "type": "object",
       "properties": {
         "prop_value": {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         }
       },
       "additionalProperties": false
@@ -172,7 +186,9 @@ fn it_tests_definitions() -> rschema::Result<()> {
       "type": "object",
       "properties": {
         "prop_value": {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         }
       },
       "additionalProperties": false
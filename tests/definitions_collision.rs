@@ -0,0 +1,142 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Collision,
+    DefinitionsMap,
+    Error,
+    Schema,
+    Schematic,
+};
+
+mod server {
+    use super::*;
+
+    #[derive(Debug, Schematic)]
+    #[rschema(defs = "Config")]
+    pub struct Config {
+        port: u16,
+    }
+}
+
+mod client {
+    use super::*;
+
+    #[derive(Debug, Schematic)]
+    #[rschema(defs = "Config")]
+    pub struct Config {
+        url: String,
+    }
+}
+
+#[derive(Debug, Schematic)]
+struct Settings {
+    server: server::Config,
+
+    client: client::Config,
+
+    backup: Option<server::Config>,
+}
+
+#[test]
+fn it_fails_with_colliding_definitions() {
+    let err = Schema::new::<Settings>("Settings")
+        .to_string()
+        .unwrap_err();
+
+    match err {
+        Error::DefinitionsCollisionError { ref name, ref first, ref second } => {
+            assert_eq!(name, "Config");
+            assert_eq!(first, "definitions_collision::server::Config");
+            assert_eq!(second, "definitions_collision::client::Config");
+        },
+        _ => panic!("unexpected error: {}", err),
+    }
+    assert_eq!(
+        err.to_string(),
+        "`$defs/Config` is defined by both `definitions_collision::server::Config` and `definitions_collision::client::Config`",
+    );
+}
+
+#[test]
+fn it_fails_to_serialize_colliding_definitions() {
+    let schema = Schema::new::<Settings>("Settings");
+    let err = serde_json::to_value(&schema).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`$defs/Config` is defined by both `definitions_collision::server::Config` and `definitions_collision::client::Config`",
+    );
+    assert!(serde_json::to_writer(std::io::sink(), &schema).is_err());
+
+    let mut schema = Schema::new::<Settings>("Settings");
+    schema.on_collision(Collision::Suffix);
+    let value = serde_json::to_value(&schema).unwrap();
+    assert_eq!(value["properties"]["client"]["$ref"], "#/$defs/Config_2");
+}
+
+#[test]
+fn it_fails_to_build_colliding_definitions() {
+    let mut defs_map = DefinitionsMap::new();
+    defs_map.extend_ty::<server::Config>();
+    defs_map.extend_ty::<client::Config>();
+
+    match defs_map.build() {
+        Err(Error::DefinitionsCollisionError { ref name, .. }) => assert_eq!(name, "Config"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn it_numbers_colliding_definitions() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Settings>("Settings")
+        .on_collision(Collision::Suffix)
+        .to_string_pretty()?;
+    let schema_str2 = r##"{
+  "title": "Settings",
+  "type": "object",
+  "properties": {
+    "server": {
+      "$ref": "#/$defs/Config"
+    },
+    "client": {
+      "$ref": "#/$defs/Config_2"
+    },
+    "backup": {
+      "anyOf": [
+        {
+          "$ref": "#/$defs/Config"
+        },
+        {
+          "type": "null"
+        }
+      ]
+    }
+  },
+  "additionalProperties": false,
+  "$defs": {
+    "Config": {
+      "type": "object",
+      "properties": {
+        "port": {
+          "type": "integer",
+          "minimum": 0,
+          "maximum": 65535
+        }
+      },
+      "additionalProperties": false
+    },
+    "Config_2": {
+      "type": "object",
+      "properties": {
+        "url": {
+          "type": "string"
+        }
+      },
+      "additionalProperties": false
+    }
+  }
+}"##;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
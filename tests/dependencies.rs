@@ -0,0 +1,128 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Draft,
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(
+    additional_properties,
+    defs = "TlsPort",
+)]
+struct TlsPort {
+    #[rschema(required)]
+    port: u16,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(
+    rename_all = "camelCase",
+    dependent(field = "tls_cert", schema = "TlsPort"),
+)]
+struct Server {
+    #[rschema(required)]
+    host: String,
+
+    #[rschema(requires(tls_key))]
+    tls_cert: Option<String>,
+
+    #[rschema(rename = "key", requires(tls_cert))]
+    tls_key: Option<String>,
+
+    port: Option<u16>,
+}
+
+#[test]
+fn it_tests_dependencies() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Server>("Server"))?;
+
+    assert_eq!(schema["dependentRequired"], json!({
+        "tlsCert": ["key"],
+        "key": ["tlsCert"],
+    }));
+    assert_eq!(schema["dependentSchemas"], json!({
+        "tlsCert": { "$ref": "#/$defs/TlsPort" },
+    }));
+    assert_eq!(schema["$defs"]["TlsPort"]["required"], json!(["port"]));
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_dependencies_in_draft7() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Server>("Server")
+        .schema(Draft::Draft7)
+        .to_string()?;
+    let schema: serde_json::Value = serde_json::from_str(&schema_str)?;
+
+    assert!(schema.get("dependentRequired").is_none());
+    assert!(schema.get("dependentSchemas").is_none());
+    assert_eq!(schema["dependencies"], json!({
+        "tlsCert": {
+            "allOf": [
+                { "required": ["key"] },
+                { "$ref": "#/definitions/TlsPort" },
+            ],
+        },
+        "key": ["tlsCert"],
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn it_reads_dependencies() -> rschema::Result<()> {
+    let schema: Schema = r#"{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "type": "object",
+  "properties": {
+    "a": {
+      "type": "string"
+    },
+    "b": {
+      "type": "string"
+    }
+  },
+  "dependencies": {
+    "a": [
+      "b"
+    ],
+    "b": {
+      "type": "object",
+      "required": [
+        "a"
+      ]
+    }
+  }
+}"#.parse()?;
+
+    let round_trip: serde_json::Value = serde_json::from_str(&schema.to_string()?)?;
+    assert_eq!(round_trip["dependencies"]["a"], json!(["b"]));
+    assert_eq!(round_trip["dependencies"]["b"]["required"], json!(["a"]));
+
+    Ok(())
+}
+
+#[test]
+fn it_validates_dependencies() {
+    let schema = Schema::new::<Server>("Server");
+
+    assert!(schema.validate(&json!({ "host": "a" })).is_ok());
+    assert!(schema.validate(&json!({ "host": "a", "tlsCert": "c", "key": "k", "port": 443 })).is_ok());
+
+    let errors = schema
+        .validate(&json!({ "host": "a", "tlsCert": "c" }))
+        .unwrap_err();
+    let schema_paths: Vec<&str> = errors
+        .iter()
+        .map(|error| error.schema_path.as_str())
+        .collect();
+
+    assert_eq!(schema_paths, [
+        "/dependentRequired/tlsCert",
+        "/$defs/TlsPort/required",
+    ]);
+}
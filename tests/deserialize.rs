@@ -0,0 +1,204 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Draft,
+    Items,
+    Schema,
+    Schematic,
+    Type,
+};
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Nested")]
+struct NestedStruct {
+    #[rschema(title = "Value")]
+    prop_value: f64,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(additional_properties)]
+struct Deserialized {
+    #[rschema(
+        title = "Name",
+        description = "Name of the item",
+        min_length = 1,
+        pattern = r"^\w+$",
+        required,
+    )]
+    prop_name: String,
+
+    #[rschema(exclusive_minimum = 0)]
+    prop_value: u8,
+
+    prop_tuple: (bool, String),
+
+    prop_nested: NestedStruct,
+
+    prop_array: Vec<NestedStruct>,
+
+    prop_option: Option<NestedStruct>,
+}
+
+#[test]
+fn it_tests_round_trip() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Deserialized>("Deserialized")
+        .schema(Draft::Draft201909)
+        .id("http://example.com/deserialized.json")
+        .to_string_pretty()?;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.schema, Some(Draft::Draft201909));
+    assert_eq!(schema.title, "Deserialized");
+    assert!(schema.defs().contains_key("Nested"));
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_older_draft() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Deserialized>("Deserialized")
+        .schema(Draft::Draft4)
+        .to_string_pretty()?;
+    let mut schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    let schema_str2 = Schema::new::<Deserialized>("Deserialized")
+        .to_string_pretty()?;
+    schema.schema(Draft::Draft201909);
+
+    assert!(schema.to_string_pretty()?.contains(r##""$ref": "#/$defs/Nested""##));
+    assert_eq!(
+        schema.to_string_pretty()?.replacen(r#"  "$schema": "https://json-schema.org/draft/2019-09/schema",
+"#, "", 1),
+        schema_str2,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_unknown_keywords() -> rschema::Result<()> {
+    let schema_str = r##"{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "Hand-written",
+  "type": "object",
+  "properties": {
+    "prop_ref": {
+      "description": "Defined below",
+      "$ref": "#/definitions/Item",
+      "x-order": 1
+    }
+  },
+  "additionalProperties": false,
+  "x-generator": "hand",
+  "definitions": {
+    "Item": {
+      "type": "string"
+    }
+  }
+}"##;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.extra["x-generator"], "hand");
+    let prop = match schema.ty() {
+        Type::Object(keys) => &keys.properties["prop_ref"],
+        ty => panic!("unexpected type: {:?}", ty),
+    };
+    assert_eq!(prop.description.as_deref(), Some("Defined below"));
+    assert_eq!(prop.extra["x-order"], 1);
+    assert!(matches!(prop.ty, Type::Ref(ref name) if name == "Item"));
+
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_external_ref() {
+    let schema_str = r#"{"title":"External","$ref":"other.json#/Item"}"#;
+
+    assert!(schema_str.parse::<Schema>().is_err());
+}
+
+#[test]
+fn it_tests_unknown_keywords_in_subschemas() -> rschema::Result<()> {
+    let schema_str = r##"{
+  "title": "Hand-written",
+  "type": "object",
+  "properties": {
+    "prop_array": {
+      "type": "array",
+      "items": {
+        "type": "integer",
+        "description": "Nested in items"
+      }
+    },
+    "prop_any_of": {
+      "anyOf": [
+        {
+          "type": "string",
+          "x-branch": 0
+        },
+        {
+          "type": "null"
+        }
+      ]
+    }
+  },
+  "additionalProperties": {
+    "type": "string",
+    "$comment": "Extra properties"
+  },
+  "dependentSchemas": {
+    "prop_array": {
+      "$ref": "#/$defs/Item",
+      "description": "Ref with siblings"
+    }
+  },
+  "$defs": {
+    "Item": {
+      "type": "string",
+      "title": "Item",
+      "x-order": 1
+    }
+  }
+}"##;
+    let schema: Schema = schema_str.parse()?;
+
+    let keys = match schema.ty() {
+        Type::Object(keys) => keys,
+        ty => panic!("unexpected type: {:?}", ty),
+    };
+    let items = match keys.properties["prop_array"].ty {
+        Type::Array(ref keys) => match *keys.items {
+            Items::Single(ref ty) => ty,
+            ref items => panic!("unexpected items: {:?}", items),
+        },
+        ref ty => panic!("unexpected type: {:?}", ty),
+    };
+    assert!(matches!(
+        **items,
+        Type::Extra(ref keys) if keys.extra["description"] == "Nested in items",
+    ));
+    assert!(matches!(
+        schema.defs()["Item"],
+        Type::Extra(ref keys) if matches!(*keys.ty, Type::String(_)) && keys.extra["x-order"] == 1,
+    ));
+
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_unsupported_schemas() {
+    let empty = r#"{"title":"Empty","type":"object","properties":{"prop":{}}}"#;
+    let boolean = r#"{"title":"Boolean","type":"array","items":true}"#;
+    let type_list = r#"{"title":"List","type":["string","null"]}"#;
+
+    assert!(empty.parse::<Schema>().is_err());
+    assert!(boolean.parse::<Schema>().is_err());
+    assert!(type_list.parse::<Schema>().is_err());
+}
@@ -0,0 +1,235 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Compatibility,
+    Constraints,
+    Definitions,
+    Schema,
+    Schematic,
+    Type,
+};
+
+mod v1 {
+    use rschema::Schematic;
+
+    #[derive(Debug, Schematic)]
+    pub enum Level {
+        Low,
+        High,
+    }
+
+    #[derive(Debug, Schematic)]
+    pub struct Config {
+        #[rschema(
+            title = "Name",
+            max_length = 10,
+            required,
+        )]
+        pub name: String,
+
+        #[rschema(maximum = 100)]
+        pub timeout: u32,
+
+        pub level: Level,
+
+        pub retries: u8,
+    }
+}
+
+mod v2 {
+    use rschema::Schematic;
+
+    #[derive(Debug, Schematic)]
+    pub enum Level {
+        Low,
+        Middle,
+    }
+
+    #[derive(Debug, Schematic)]
+    pub struct Config {
+        #[rschema(
+            title = "Config name",
+            max_length = 20,
+            required,
+        )]
+        pub name: String,
+
+        #[rschema(
+            maximum = 50,
+            required,
+        )]
+        pub timeout: u32,
+
+        pub level: Level,
+
+        #[rschema(required)]
+        pub region: String,
+    }
+}
+
+mod v3 {
+    use rschema::Schematic;
+
+    #[derive(Debug, Schematic)]
+    #[rschema(additional_properties)]
+    pub struct Config {
+        #[rschema(
+            title = "Name",
+            max_length = 20,
+            required,
+        )]
+        pub name: String,
+
+        #[rschema(maximum = 100)]
+        pub timeout: u32,
+    }
+}
+
+mod v4 {
+    use rschema::Schematic;
+
+    #[derive(Debug, Schematic)]
+    pub struct Profile {
+        pub name: String,
+    }
+}
+
+mod v5 {
+    use rschema::Schematic;
+
+    #[derive(Debug, Schematic)]
+    pub struct Profile {
+        pub name: Option<String>,
+    }
+}
+
+#[test]
+fn it_tests_breaking_changes() -> rschema::Result<()> {
+    let diff = rschema::diff(
+        &Schema::new::<v1::Config>("Config"),
+        &Schema::new::<v2::Config>("Config"),
+    );
+
+    assert!(diff.is_breaking());
+    assert_eq!(
+        diff.to_string(),
+        r#"[full] /properties/name/title: `title` changed from "Name" to "Config name"
+[backward] /properties/name/maxLength: `maxLength` changed from 10 to 20
+[forward] /properties/timeout/maximum: `maximum` changed from 100 to 50
+[forward] /properties/level/enum: enum value "High" removed
+[backward] /properties/level/enum: enum value "Middle" added
+[forward] /properties/retries: property `retries` removed
+[breaking] /properties/region: required property `region` added
+[forward] /required: property `timeout` became required
+"#,
+    );
+
+    let report = serde_json::to_value(&diff)?;
+    assert_eq!(report["compatibility"], "breaking");
+    assert_eq!(
+        report["changes"][2],
+        serde_json::json!({
+            "path": "/properties/timeout/maximum",
+            "compatibility": "forward",
+            "message": "`maximum` changed from 100 to 50",
+            "old": 100,
+            "new": 50,
+        }),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_backward_changes() {
+    let diff = rschema::diff(
+        &Schema::new::<v1::Config>("Config"),
+        &Schema::new::<v3::Config>("Config"),
+    );
+
+    assert_eq!(diff.compatibility, Compatibility::Backward);
+    assert_eq!(
+        diff.to_string(),
+        r#"[backward] /properties/name/maxLength: `maxLength` changed from 10 to 20
+[backward] /properties/level: property `level` removed
+[backward] /properties/retries: property `retries` removed
+[backward] /additionalProperties: `additionalProperties` changed from false to true
+"#,
+    );
+}
+
+#[test]
+fn it_tests_no_changes() {
+    let diff = rschema::diff(
+        &Schema::new::<v1::Config>("Config"),
+        &Schema::new::<v1::Config>("Config"),
+    );
+
+    assert_eq!(diff.compatibility, Compatibility::Full);
+    assert!(diff.changes.is_empty());
+}
+
+#[test]
+fn it_tests_optional_fields() {
+    let diff = rschema::diff(
+        &Schema::new::<v4::Profile>("Profile"),
+        &Schema::new::<v5::Profile>("Profile"),
+    );
+
+    assert_eq!(diff.compatibility, Compatibility::Backward);
+    assert_eq!(
+        diff.to_string(),
+        "[backward] /properties/name/anyOf/1: branch 1 added\n",
+    );
+
+    let diff = rschema::diff(
+        &Schema::new::<v5::Profile>("Profile"),
+        &Schema::new::<v4::Profile>("Profile"),
+    );
+
+    assert_eq!(diff.compatibility, Compatibility::Forward);
+    assert_eq!(
+        diff.to_string(),
+        "[forward] /properties/name/anyOf/1: branch 1 removed\n",
+    );
+}
+
+#[test]
+fn it_tests_reordered_branches() -> rschema::Result<()> {
+    let old: Schema = r#"{"anyOf":[{"type":"string","maxLength":10},{"type":"integer"}]}"#.parse()?;
+    let new: Schema = r#"{"anyOf":[{"type":"integer"},{"type":"string","maxLength":20},{"type":"null"}]}"#.parse()?;
+
+    let diff = rschema::diff(&old, &new);
+
+    assert_eq!(diff.compatibility, Compatibility::Backward);
+    assert_eq!(
+        diff.to_string(),
+        r#"[backward] /anyOf/1/maxLength: `maxLength` changed from 10 to 20
+[backward] /anyOf/2: branch 2 added
+"#,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_types() {
+    let diff = rschema::diff_types(
+        &<Option<u8>>::schema(Constraints::default()),
+        &u8::schema(Constraints::default()),
+        &Definitions::new(),
+        &Definitions::new(),
+    );
+
+    assert_eq!(diff.compatibility, Compatibility::Forward);
+    assert_eq!(diff.to_string(), "[forward] /anyOf/1: branch 1 removed\n");
+
+    let diff = rschema::diff_types(
+        &Type::string().build(),
+        &Type::integer().build(),
+        &Definitions::new(),
+        &Definitions::new(),
+    );
+
+    assert!(diff.is_breaking());
+}
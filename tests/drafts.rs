@@ -0,0 +1,195 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Draft,
+    Schema,
+    Schematic,
+};
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Nested")]
+struct NestedStruct {
+    #[rschema(
+        minimum = 0,
+        exclusive_maximum = 1.5,
+    )]
+    prop_value: f64,
+}
+
+#[derive(Debug, Schematic)]
+struct Drafts {
+    #[rschema(exclusive_minimum = 0)]
+    prop_value: u8,
+
+    prop_tuple: (bool, String),
+
+    prop_nested: NestedStruct,
+}
+
+#[test]
+fn it_tests_draft4() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Drafts>("Draft 4")
+        .schema(Draft::Draft4)
+        .id("http://example.com/drafts.json")
+        .to_string_pretty()?;
+    let schema_str2 = r##"{
+  "$schema": "http://json-schema.org/draft-04/schema#",
+  "id": "http://example.com/drafts.json",
+  "title": "Draft 4",
+  "type": "object",
+  "properties": {
+    "prop_value": {
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 255,
+      "exclusiveMinimum": true
+    },
+    "prop_tuple": {
+      "type": "array",
+      "items": [
+        {
+          "type": "boolean"
+        },
+        {
+          "type": "string"
+        }
+      ],
+      "additionalItems": false,
+      "minItems": 2,
+      "maxItems": 2
+    },
+    "prop_nested": {
+      "$ref": "#/definitions/Nested"
+    }
+  },
+  "additionalProperties": false,
+  "definitions": {
+    "Nested": {
+      "type": "object",
+      "properties": {
+        "prop_value": {
+          "type": "number",
+          "minimum": 0,
+          "maximum": 1.5,
+          "exclusiveMaximum": true
+        }
+      },
+      "additionalProperties": false
+    }
+  }
+}"##;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_draft7() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Drafts>("Draft 7")
+        .schema(Draft::Draft7)
+        .to_string_pretty()?;
+    let schema_str2 = r##"{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "Draft 7",
+  "type": "object",
+  "properties": {
+    "prop_value": {
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 255,
+      "exclusiveMinimum": 0
+    },
+    "prop_tuple": {
+      "type": "array",
+      "items": [
+        {
+          "type": "boolean"
+        },
+        {
+          "type": "string"
+        }
+      ],
+      "additionalItems": false,
+      "minItems": 2,
+      "maxItems": 2
+    },
+    "prop_nested": {
+      "$ref": "#/definitions/Nested"
+    }
+  },
+  "additionalProperties": false,
+  "definitions": {
+    "Nested": {
+      "type": "object",
+      "properties": {
+        "prop_value": {
+          "type": "number",
+          "minimum": 0,
+          "exclusiveMaximum": 1.5
+        }
+      },
+      "additionalProperties": false
+    }
+  }
+}"##;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_draft202012() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Drafts>("Draft 2020-12")
+        .schema(Draft::Draft202012)
+        .to_string_pretty()?;
+    let schema_str2 = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "title": "Draft 2020-12",
+  "type": "object",
+  "properties": {
+    "prop_value": {
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 255,
+      "exclusiveMinimum": 0
+    },
+    "prop_tuple": {
+      "type": "array",
+      "prefixItems": [
+        {
+          "type": "boolean"
+        },
+        {
+          "type": "string"
+        }
+      ],
+      "items": false,
+      "minItems": 2,
+      "maxItems": 2
+    },
+    "prop_nested": {
+      "$ref": "#/$defs/Nested"
+    }
+  },
+  "additionalProperties": false,
+  "$defs": {
+    "Nested": {
+      "type": "object",
+      "properties": {
+        "prop_value": {
+          "type": "number",
+          "minimum": 0,
+          "exclusiveMaximum": 1.5
+        }
+      },
+      "additionalProperties": false
+    }
+  }
+}"##;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
@@ -5,6 +5,8 @@ use rschema::{
     Schematic,
 };
 
+// The variants are named after the kinds of variants under test.
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Schematic)]
 enum Enum {
     EmptyTupleVariant(),
@@ -57,12 +59,13 @@ fn it_tests_enum_properties() -> rschema::Result<()> {
       "anyOf": [
         {
           "type": "array",
-          "items": [],
           "minItems": 0,
           "maxItems": 0
         },
         {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         {
           "type": "array",
@@ -76,6 +79,7 @@ fn it_tests_enum_properties() -> rschema::Result<()> {
               "type": "boolean"
             }
           ],
+          "additionalItems": false,
           "minItems": 2,
           "maxItems": 2
         },
@@ -84,7 +88,9 @@ fn it_tests_enum_properties() -> rschema::Result<()> {
           "properties": {
             "value": {
               "title": "value",
-              "type": "number"
+              "type": "integer",
+              "minimum": -2147483648,
+              "maximum": 2147483647
             }
           },
           "additionalProperties": true
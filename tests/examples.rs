@@ -0,0 +1,172 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde::Serialize;
+use serde_json::json;
+
+fn example_tags() -> Vec<String> {
+    vec!["new".into(), "sale".into()]
+}
+
+fn example_config() -> Config {
+    Config {
+        version: 2,
+        name: "app".into(),
+        tags: vec![],
+        ratio: 0.5,
+    }
+}
+
+fn current_version() -> Pinned {
+    Pinned(Config {
+        version: 2,
+        name: "current".into(),
+        tags: example_tags(),
+        ratio: 1.0,
+    })
+}
+
+#[derive(Debug, Serialize, Schematic)]
+#[rschema(example = "example_config")]
+struct Config {
+    #[rschema(const = 2)]
+    version: u32,
+
+    #[rschema(examples("app", "server"))]
+    name: String,
+
+    #[rschema(example = "example_tags")]
+    tags: Vec<String>,
+
+    #[rschema(examples(0.5, 1.0))]
+    ratio: f64,
+}
+
+#[derive(Debug, Serialize, Schematic)]
+enum Level {
+    Low,
+    High,
+}
+
+fn low() -> Threshold {
+    Threshold(Level::Low)
+}
+
+fn high() -> Threshold {
+    Threshold(Level::High)
+}
+
+#[derive(Debug, Serialize, Schematic)]
+#[rschema(examples("low", "high"))]
+struct Threshold(Level);
+
+#[derive(Debug, Serialize, Schematic)]
+#[rschema(const = "current_version")]
+struct Pinned(Config);
+
+#[test]
+fn it_tests_field_examples_and_const() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Config>("Config")
+        .to_string_pretty()?;
+    let schema_str2 = r#"{
+  "title": "Config",
+  "examples": [
+    {
+      "version": 2,
+      "name": "app",
+      "tags": [],
+      "ratio": 0.5
+    }
+  ],
+  "type": "object",
+  "properties": {
+    "version": {
+      "const": 2,
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 4294967295
+    },
+    "name": {
+      "examples": [
+        "app",
+        "server"
+      ],
+      "type": "string"
+    },
+    "tags": {
+      "examples": [
+        [
+          "new",
+          "sale"
+        ]
+      ],
+      "type": "array",
+      "items": {
+        "type": "string"
+      }
+    },
+    "ratio": {
+      "examples": [
+        0.5,
+        1.0
+      ],
+      "type": "number"
+    }
+  },
+  "additionalProperties": false
+}"#;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_container_const() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Pinned>("Pinned"))?;
+
+    assert_eq!(schema["const"]["name"], json!("current"));
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_container_examples() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Threshold>("Threshold"))?;
+
+    assert_eq!(schema["examples"], json!(["Low", "High"]));
+
+    Ok(())
+}
+
+#[test]
+fn it_validates_const() -> rschema::Result<()> {
+    let schema = Schema::new::<Config>("Config");
+    let errors = schema
+        .validate(&json!({ "version": 3, "name": "app", "tags": [], "ratio": 1 }))
+        .unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].instance_path, "/version");
+    assert_eq!(errors[0].schema_path, "/properties/version/const");
+
+    let schema = Schema::new::<Pinned>("Pinned");
+    assert!(schema.validate(&serde_json::to_value(current_version())?).is_ok());
+    assert!(schema.validate(&serde_json::to_value(example_config())?).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_round_trip() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Config>("Config")
+        .to_string_pretty()?;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
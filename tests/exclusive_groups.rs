@@ -0,0 +1,184 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+    Type,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(rename_all = "camelCase")]
+struct Client {
+    #[rschema(exclusive_group = "auth")]
+    token: Option<String>,
+
+    #[rschema(exclusive_group = "auth")]
+    token_file: Option<String>,
+
+    #[rschema(exclusive_group = "auth", rename = "env")]
+    token_env: Option<String>,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(
+    exclusive_group(name = "auth", required),
+    when(kind = "proxy", require(url)),
+)]
+struct Upstream {
+    kind: Option<String>,
+
+    url: Option<String>,
+
+    #[rschema(exclusive_group = "auth")]
+    token: Option<String>,
+
+    #[rschema(exclusive_group = "auth")]
+    password: Option<String>,
+}
+
+#[test]
+fn it_tests_exclusive_groups() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Client>("Client"))?;
+
+    assert_eq!(schema["not"], json!({
+        "anyOf": [
+            { "required": ["token", "tokenFile"] },
+            { "required": ["token", "env"] },
+            { "required": ["tokenFile", "env"] },
+        ],
+    }));
+    assert!(schema.get("allOf").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_required_exclusive_groups() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Upstream>("Upstream"))?;
+
+    assert_eq!(schema["allOf"][0]["if"]["required"], json!(["kind"]));
+    assert_eq!(schema["allOf"][1], json!({
+        "oneOf": [
+            { "required": ["token"] },
+            { "required": ["password"] },
+        ],
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn it_validates_exclusive_groups() {
+    let schema = Schema::new::<Client>("Client");
+
+    assert!(schema.validate(&json!({})).is_ok());
+    assert!(schema.validate(&json!({ "tokenFile": "/run/token" })).is_ok());
+
+    let errors = schema
+        .validate(&json!({ "token": "t", "env": "TOKEN" }))
+        .unwrap_err();
+    let schema_paths: Vec<&str> = errors
+        .iter()
+        .map(|error| error.schema_path.as_str())
+        .collect();
+
+    assert_eq!(schema_paths, ["/not"]);
+}
+
+#[test]
+fn it_validates_required_exclusive_groups() {
+    let schema = Schema::new::<Upstream>("Upstream");
+
+    assert!(schema.validate(&json!({ "token": "t" })).is_ok());
+
+    for instance in [json!({}), json!({ "token": "t", "password": "p" })] {
+        let errors = schema.validate(&instance).unwrap_err();
+        let schema_paths: Vec<&str> = errors
+            .iter()
+            .map(|error| error.schema_path.as_str())
+            .collect();
+
+        assert_eq!(schema_paths, ["/allOf/1/oneOf"]);
+    }
+}
+
+#[test]
+fn it_reads_exclusive_groups() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Upstream>("Upstream").to_string()?;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string()?, schema_str);
+
+    Ok(())
+}
+
+#[test]
+fn it_keeps_other_subschemas() -> rschema::Result<()> {
+    let schema_str = r#"{
+  "title": "Mixed",
+  "type": "object",
+  "properties": {
+    "token": {
+      "type": "string"
+    },
+    "password": {
+      "type": "string"
+    }
+  },
+  "additionalProperties": false,
+  "allOf": [
+    {
+      "oneOf": [
+        {
+          "required": [
+            "token"
+          ]
+        },
+        {
+          "required": [
+            "password"
+          ]
+        }
+      ]
+    },
+    {
+      "not": {
+        "required": [
+          "token",
+          "password",
+          "user"
+        ]
+      }
+    },
+    {
+      "minProperties": 1
+    }
+  ]
+}"#;
+    let schema: Schema = schema_str.parse()?;
+
+    let keys = match schema.ty() {
+        Type::Object(keys) => keys,
+        ty => panic!("unexpected type: {:?}", ty),
+    };
+    assert_eq!(keys.exclusive_groups.len(), 1);
+    assert_eq!(keys.other_subschemas, [
+        json!({ "not": { "required": ["token", "password", "user"] } }),
+        json!({ "minProperties": 1 }),
+    ]);
+
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
+
+#[test]
+fn it_keeps_a_single_other_subschema() -> rschema::Result<()> {
+    let schema_str = r#"{"title":"Single","type":"object","properties":{},"additionalProperties":false,"allOf":[{"required":["a"]}]}"#;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string()?, schema_str);
+
+    Ok(())
+}
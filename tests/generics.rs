@@ -0,0 +1,137 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Naming,
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, Schematic)]
+struct Page<T> {
+    items: Vec<T>,
+
+    total: u32,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Page")]
+struct DefinedPage<T> {
+    items: Vec<T>,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(defs)]
+struct Tree<T> {
+    value: T,
+
+    children: Vec<Tree<T>>,
+}
+
+#[derive(Debug, Schematic)]
+enum Either<L, R> {
+    Left(L),
+
+    Right(R),
+}
+
+#[derive(Debug, Schematic)]
+struct Pair<A, B>(A, B);
+
+#[derive(Debug, Schematic)]
+struct Wrapper<T>(T);
+
+trait Kind {
+    type Value;
+}
+
+struct Text;
+
+impl Kind for Text {
+    type Value = String;
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(bound = "K::Value: Schematic")]
+struct Tagged<K: Kind> {
+    value: K::Value,
+}
+
+#[derive(Debug, Schematic)]
+struct Listing {
+    users: DefinedPage<User>,
+
+    names: DefinedPage<String>,
+
+    tree: Tree<i32>,
+}
+
+fn to_json(schema: &Schema) -> serde_json::Value {
+    serde_json::from_str(&schema.to_string().unwrap()).unwrap()
+}
+
+#[test]
+fn it_bounds_type_parameters() {
+    let schema = to_json(&Schema::new::<Page<User>>("Page"));
+
+    assert_eq!(schema["properties"]["items"]["items"]["properties"]["name"], json!({ "type": "string" }));
+    assert_eq!(schema["properties"]["total"]["type"], "integer");
+}
+
+#[test]
+fn it_bounds_type_parameters_of_enums_and_tuple_structs() {
+    let schema = to_json(&Schema::new::<Either<Pair<bool, String>, Wrapper<i32>>>("Either"));
+
+    assert_eq!(
+        schema["anyOf"],
+        json!([
+            {
+                "type": "array",
+                "items": [
+                    { "type": "boolean" },
+                    { "type": "string" },
+                ],
+                "additionalItems": false,
+                "minItems": 2,
+                "maxItems": 2,
+            },
+            {
+                "type": "integer",
+                "minimum": -2147483648,
+                "maximum": 2147483647,
+            },
+        ]),
+    );
+}
+
+#[test]
+fn it_uses_the_given_bound() {
+    let schema = to_json(&Schema::new::<Tagged<Text>>("Tagged"));
+
+    assert_eq!(schema["properties"]["value"], json!({ "type": "string" }));
+}
+
+#[test]
+fn it_defines_each_instantiation() {
+    let schema = to_json(&Schema::new::<Listing>("Listing"));
+
+    assert_eq!(schema["properties"]["users"]["$ref"], "#/$defs/Page_User");
+    assert_eq!(schema["properties"]["names"]["$ref"], "#/$defs/Page_String");
+    assert_eq!(schema["$defs"]["Page_User"]["properties"]["items"]["items"]["properties"]["name"], json!({ "type": "string" }));
+    assert_eq!(schema["$defs"]["Page_String"]["properties"]["items"]["items"], json!({ "type": "string" }));
+}
+
+#[test]
+fn it_names_instantiations_with_generic_arguments() {
+    let mut schema = Schema::new::<Listing>("Listing");
+    schema.naming(Naming::IdentWithGenerics);
+    let schema = to_json(&schema);
+
+    assert_eq!(schema["properties"]["tree"]["$ref"], "#/$defs/Tree_i32");
+    assert_eq!(schema["$defs"]["Tree_i32"]["properties"]["children"]["items"]["$ref"], "#/$defs/Tree_i32");
+}
@@ -0,0 +1,98 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Constraints,
+    DefinitionsMap,
+    Schema,
+    Schematic,
+    StringKeys,
+    Type,
+};
+
+#[derive(Debug)]
+struct Uuid(u128);
+
+impl Schematic for Uuid {
+    fn schema(constraints: Constraints) -> Type {
+        Type::String(StringKeys {
+            format: constraints.format.or_else(|| Some("uuid".into())),
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Color;
+
+impl Schematic for Color {
+    fn schema(_: Constraints) -> Type {
+        Type::Ref(std::any::type_name::<Self>().into())
+    }
+
+    fn definitions() -> DefinitionsMap {
+        let mut defs_map = DefinitionsMap::new();
+        defs_map.insert::<Self>("Color", String::schema(Constraints::default()));
+        defs_map
+    }
+}
+
+#[derive(Debug, Schematic)]
+struct Theme {
+    id: Uuid,
+
+    #[rschema(format = "hex")]
+    legacy_id: Uuid,
+
+    colors: Vec<Color>,
+}
+
+#[test]
+fn it_uses_manual_implementations() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Theme>("Theme")
+        .to_string_pretty()?;
+    let schema_str2 = r##"{
+  "title": "Theme",
+  "type": "object",
+  "properties": {
+    "id": {
+      "type": "string",
+      "format": "uuid"
+    },
+    "legacy_id": {
+      "type": "string",
+      "format": "hex"
+    },
+    "colors": {
+      "type": "array",
+      "items": {
+        "$ref": "#/$defs/Color"
+      }
+    }
+  },
+  "additionalProperties": false,
+  "$defs": {
+    "Color": {
+      "type": "string"
+    }
+  }
+}"##;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
+
+#[test]
+fn it_passes_constraints_to_built_in_implementations() {
+    let mut constraints = Constraints::default();
+    constraints.min_items = Some(1);
+    constraints.unique_items = Some(true);
+
+    match Vec::<String>::schema(constraints) {
+        Type::Array(keys) => {
+            assert_eq!(keys.min_items, Some(1));
+            assert_eq!(keys.unique_items, Some(true));
+        },
+        ty => panic!("unexpected type: {:?}", ty),
+    }
+}
@@ -0,0 +1,163 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Collision,
+    Naming,
+    Schema,
+    Schematic,
+};
+
+mod models {
+    use super::*;
+
+    #[derive(Debug, Schematic)]
+    #[rschema(defs)]
+    pub struct User {
+        name: String,
+    }
+
+    pub mod legacy {
+        use super::*;
+
+        #[derive(Debug, Schematic)]
+        #[rschema(defs)]
+        pub struct User {
+            id: i32,
+        }
+    }
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Settings")]
+struct Config {
+    verbose: bool,
+}
+
+#[derive(Debug, Schematic)]
+struct Account {
+    user: models::User,
+
+    friends: Vec<models::User>,
+
+    config: Config,
+}
+
+#[derive(Debug, Schematic)]
+struct Migration {
+    from: models::legacy::User,
+
+    to: models::User,
+}
+
+fn refs(schema: &Schema) -> Vec<String> {
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string().unwrap()).unwrap();
+    vec![
+        value["properties"]["user"]["$ref"].as_str().unwrap().into(),
+        value["properties"]["friends"]["items"]["$ref"].as_str().unwrap().into(),
+        value["properties"]["config"]["$ref"].as_str().unwrap().into(),
+    ]
+}
+
+#[test]
+fn it_names_definitions_with_full_paths_by_default() {
+    let schema = Schema::new::<Account>("Account");
+
+    assert_eq!(
+        schema.defs().keys().collect::<Vec<_>>(),
+        ["naming::models::User", "Settings"],
+    );
+    assert_eq!(
+        refs(&schema),
+        ["#/$defs/naming::models::User", "#/$defs/naming::models::User", "#/$defs/Settings"],
+    );
+}
+
+#[test]
+fn it_names_definitions_with_idents() {
+    let mut schema = Schema::new::<Account>("Account");
+    schema.naming(Naming::Ident);
+
+    assert_eq!(
+        schema.defs().keys().collect::<Vec<_>>(),
+        ["User", "Settings"],
+    );
+    assert_eq!(
+        refs(&schema),
+        ["#/$defs/User", "#/$defs/User", "#/$defs/Settings"],
+    );
+}
+
+#[test]
+fn it_names_definitions_with_a_closure() {
+    let mut schema = Schema::new::<Account>("Account");
+    schema.naming(Naming::custom(|name| name.replace("::", ".")));
+
+    assert_eq!(
+        schema.defs().keys().collect::<Vec<_>>(),
+        ["naming.models.User", "Settings"],
+    );
+    assert_eq!(
+        refs(&schema),
+        ["#/$defs/naming.models.User", "#/$defs/naming.models.User", "#/$defs/Settings"],
+    );
+}
+
+#[test]
+fn it_renames_definitions_again() {
+    let mut schema = Schema::new::<Account>("Account");
+    schema
+        .naming(Naming::Ident)
+        .naming(Naming::FullPath);
+
+    assert_eq!(
+        refs(&schema),
+        ["#/$defs/naming::models::User", "#/$defs/naming::models::User", "#/$defs/Settings"],
+    );
+}
+
+#[test]
+fn it_detects_collisions_of_idents() {
+    let mut schema = Schema::new::<Migration>("Migration");
+    schema.naming(Naming::Ident);
+
+    assert_eq!(
+        schema.to_string().unwrap_err().to_string(),
+        "`$defs/User` is defined by both `naming::models::legacy::User` and `naming::models::User`",
+    );
+
+    schema.on_collision(Collision::Suffix);
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string().unwrap()).unwrap();
+
+    assert_eq!(value["properties"]["from"]["$ref"], "#/$defs/User");
+    assert_eq!(value["properties"]["to"]["$ref"], "#/$defs/User_2");
+    assert_eq!(value["$defs"]["User_2"]["properties"]["name"]["type"], "string");
+}
+
+
+#[test]
+fn it_tells_origins_of_definitions() {
+    let defs_map = Account::definitions();
+    let origins: Vec<_> = defs_map
+        .iter()
+        .map(|(origin, _)| (origin.name(), origin.ty_name()))
+        .collect();
+
+    assert_eq!(
+        origins,
+        [(None, "naming::models::User"), (Some("Settings"), "naming::Config")],
+    );
+}
+
+#[test]
+fn it_keeps_names_of_definitions_read_from_strings() {
+    let mut schema: Schema = r##"{
+        "type": "object",
+        "properties": { "user": { "$ref": "#/$defs/naming::models::User" } },
+        "$defs": { "naming::models::User": { "type": "string" } }
+    }"##.parse().unwrap();
+    schema.naming(Naming::Ident);
+    let value: serde_json::Value = serde_json::from_str(&schema.to_string().unwrap()).unwrap();
+
+    assert_eq!(value["properties"]["user"]["$ref"], "#/$defs/naming::models::User");
+    assert!(value["$defs"]["naming::models::User"].is_object());
+}
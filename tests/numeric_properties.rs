@@ -52,6 +52,16 @@ struct NumericProperties {
         exclusive_maximum = 5,
     )]
     prop_all_keywords: i32,
+
+    #[rschema(
+        title = "Decimal keywords",
+        minimum = -1,
+        maximum = 0.95,
+        multiple_of = 0.01,
+        exclusive_minimum = "-0.5",
+        exclusive_maximum = 1.0,
+    )]
+    prop_decimal_keywords: f64,
 }
 
 #[test]
@@ -64,43 +74,63 @@ fn it_tests_numeric_properties() -> rschema::Result<()> {
   "properties": {
     "prop_i8": {
       "title": "i8",
-      "type": "number"
+      "type": "integer",
+      "minimum": -128,
+      "maximum": 127
     },
     "prop_i16": {
       "title": "i16",
-      "type": "number"
+      "type": "integer",
+      "minimum": -32768,
+      "maximum": 32767
     },
     "prop_i32": {
       "title": "i32",
-      "type": "number"
+      "type": "integer",
+      "minimum": -2147483648,
+      "maximum": 2147483647
     },
     "prop_i64": {
       "title": "i64",
-      "type": "number"
+      "type": "integer",
+      "minimum": -9223372036854775808,
+      "maximum": 9223372036854775807
     },
     "prop_isize": {
       "title": "isize",
-      "type": "number"
+      "type": "integer",
+      "minimum": -9223372036854775808,
+      "maximum": 9223372036854775807
     },
     "prop_u8": {
       "title": "u8",
-      "type": "number"
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 255
     },
     "prop_u16": {
       "title": "u16",
-      "type": "number"
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 65535
     },
     "prop_u32": {
       "title": "u32",
-      "type": "number"
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 4294967295
     },
     "prop_u64": {
       "title": "u64",
-      "type": "number"
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 18446744073709551615
     },
     "prop_usize": {
       "title": "usize",
-      "type": "number"
+      "type": "integer",
+      "minimum": 0,
+      "maximum": 18446744073709551615
     },
     "prop_f32": {
       "title": "f32",
@@ -112,12 +142,21 @@ fn it_tests_numeric_properties() -> rschema::Result<()> {
     },
     "prop_all_keywords": {
       "title": "All keywords",
-      "type": "number",
+      "type": "integer",
       "minimum": 1,
       "maximum": 2,
       "multipleOf": 3,
       "exclusiveMinimum": 4,
       "exclusiveMaximum": 5
+    },
+    "prop_decimal_keywords": {
+      "title": "Decimal keywords",
+      "type": "number",
+      "minimum": -1,
+      "maximum": 0.95,
+      "multipleOf": 0.01,
+      "exclusiveMinimum": -0.5,
+      "exclusiveMaximum": 1.0
     }
   },
   "additionalProperties": false
@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde_json::json;
+
+use std::collections::HashMap;
+
+#[derive(Debug, Schematic)]
+#[rschema(
+    pattern_properties(pattern = "^x-", schema = "String"),
+)]
+struct Service {
+    #[rschema(required)]
+    name: String,
+
+    #[rschema(
+        min_properties = 1,
+        max_properties = 8,
+        property_names(pattern = "^[a-z_]+$", max_length = 16),
+    )]
+    labels: HashMap<String, String>,
+}
+
+#[test]
+fn it_tests_object_keys() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Service>("Service"))?;
+
+    assert_eq!(schema["patternProperties"], json!({
+        "^x-": { "type": "string" },
+    }));
+    assert_eq!(schema["properties"]["labels"], json!({
+        "type": "object",
+        "properties": {},
+        "additionalProperties": { "type": "string" },
+        "propertyNames": {
+            "type": "string",
+            "maxLength": 16,
+            "pattern": "^[a-z_]+$",
+        },
+        "minProperties": 1,
+        "maxProperties": 8,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn it_validates_object_keys() {
+    let schema = Schema::new::<Service>("Service");
+
+    assert!(schema.validate(&json!({ "name": "a", "labels": { "app": "web" }, "x-team": "core" })).is_ok());
+
+    let errors = schema
+        .validate(&json!({ "name": "a", "labels": {}, "x-team": 1, "extra": true }))
+        .unwrap_err();
+    let schema_paths: Vec<&str> = errors
+        .iter()
+        .map(|error| error.schema_path.as_str())
+        .collect();
+
+    assert_eq!(schema_paths, [
+        "/properties/labels/minProperties",
+        "/patternProperties/^x-/type",
+        "/additionalProperties",
+    ]);
+
+    let errors = schema
+        .validate(&json!({ "name": "a", "labels": { "App": "web" } }))
+        .unwrap_err();
+
+    assert_eq!(errors[0].instance_path, "/labels/App");
+    assert_eq!(errors[0].schema_path, "/properties/labels/propertyNames/pattern");
+}
+
+#[test]
+fn it_reads_object_keys() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Service>("Service").to_string()?;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string()?, schema_str);
+
+    Ok(())
+}
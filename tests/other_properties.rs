@@ -37,7 +37,9 @@ fn it_tests_other_properties() -> rschema::Result<()> {
       "title": "Option<i32>",
       "anyOf": [
         {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         {
           "type": "null"
@@ -46,21 +48,27 @@ fn it_tests_other_properties() -> rschema::Result<()> {
     },
     "prop_box": {
       "title": "Box<i32>",
-      "type": "number"
+      "type": "integer",
+      "minimum": -2147483648,
+      "maximum": 2147483647
     },
     "prop_hashmap": {
       "title": "HashMap<String, i32>",
       "type": "object",
       "properties": {},
       "additionalProperties": {
-        "type": "number"
+        "type": "integer",
+        "minimum": -2147483648,
+        "maximum": 2147483647
       }
     },
     "prop_hashset": {
       "title": "HashSet<i32>",
       "type": "array",
       "items": {
-        "type": "number"
+        "type": "integer",
+        "minimum": -2147483648,
+        "maximum": 2147483647
       },
       "uniqueItems": true
     }
@@ -0,0 +1,153 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+
+#[derive(Debug, Schematic)]
+struct User {
+    #[rschema(read_only)]
+    id: u64,
+
+    name: String,
+
+    #[rschema(write_only)]
+    password: String,
+
+    audit: Audit,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(read_only)]
+struct Audit {
+    created_at: String,
+
+    updated_at: String,
+
+    #[rschema(read_only = false)]
+    note: String,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(write_only)]
+struct Credentials(String, String);
+
+#[derive(Debug, Schematic)]
+#[rschema(read_only)]
+enum Status {
+    Active,
+    Closed,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(write_only)]
+struct Token(String);
+
+#[derive(Debug, Schematic)]
+#[rschema(read_only)]
+struct Marker;
+
+#[derive(Debug, Schematic)]
+struct Session {
+    status: Status,
+
+    token: Token,
+
+    marker: Marker,
+
+    #[rschema(write_only = false)]
+    public_token: Token,
+}
+
+#[derive(Debug, Schematic)]
+struct Account {
+    previous: Option<Box<Audit>>,
+
+    credentials: Credentials,
+
+    #[rschema(read_only = false)]
+    editable: Audit,
+}
+
+#[test]
+fn it_tests_field_flags() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<User>("User"))?;
+    let properties = &schema["properties"];
+
+    assert_eq!(properties["id"]["readOnly"], true);
+    assert_eq!(properties["password"]["writeOnly"], true);
+    assert!(properties["name"].get("readOnly").is_none());
+    assert!(properties["name"].get("writeOnly").is_none());
+    assert_eq!(properties["audit"]["readOnly"], true);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_flags_of_referenced_containers() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Account>("Account"))?;
+    let properties = &schema["properties"];
+
+    assert_eq!(properties["previous"]["readOnly"], true);
+    assert_eq!(properties["credentials"]["writeOnly"], true);
+    assert!(properties["editable"].get("readOnly").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_flags_of_enums_and_other_structs() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Session>("Session"))?;
+    let properties = &schema["properties"];
+
+    assert_eq!(properties["status"]["readOnly"], true);
+    assert_eq!(properties["token"]["writeOnly"], true);
+    assert_eq!(properties["marker"]["readOnly"], true);
+    assert!(properties["public_token"].get("writeOnly").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_container_flags() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Audit>("Audit")
+        .to_string_pretty()?;
+    let schema_str2 = r#"{
+  "title": "Audit",
+  "type": "object",
+  "properties": {
+    "created_at": {
+      "readOnly": true,
+      "type": "string"
+    },
+    "updated_at": {
+      "readOnly": true,
+      "type": "string"
+    },
+    "note": {
+      "type": "string"
+    }
+  },
+  "additionalProperties": false
+}"#;
+
+    assert_eq!(schema_str, schema_str2);
+
+    let schema = serde_json::to_value(Schema::new::<Credentials>("Credentials"))?;
+    assert_eq!(schema["items"][0]["writeOnly"], true);
+    assert_eq!(schema["items"][1]["writeOnly"], true);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_round_trip() -> rschema::Result<()> {
+    let schema_str = Schema::new::<User>("User")
+        .to_string_pretty()?;
+    let schema: Schema = schema_str.parse()?;
+
+    assert_eq!(schema.to_string_pretty()?, schema_str);
+
+    Ok(())
+}
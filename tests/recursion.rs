@@ -0,0 +1,185 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde_json::{
+    Value,
+    json,
+};
+
+#[derive(Debug, Schematic)]
+struct Node {
+    #[rschema(required)]
+    name: String,
+
+    children: Vec<Node>,
+}
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Expr")]
+enum Expr {
+    Literal(i64),
+    Neg(Box<Expr>),
+    Add(Box<Self>, Box<Self>),
+}
+
+// Org -> Team -> Member -> Org
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Org")]
+struct Org {
+    teams: Vec<Team>,
+}
+
+#[derive(Debug, Schematic)]
+struct Team {
+    members: Vec<Member>,
+}
+
+#[derive(Debug, Schematic)]
+struct Member {
+    org: Option<Box<Org>>,
+}
+
+// A -> B -> A, without `defs`
+#[derive(Debug, Schematic)]
+struct A {
+    b: Vec<B>,
+}
+
+#[derive(Debug, Schematic)]
+struct B {
+    a: Option<Box<A>>,
+}
+
+mod other {
+    use rschema::Schematic;
+
+    #[derive(Debug, Schematic)]
+    pub struct Item {
+        pub name: String,
+    }
+}
+
+// Not recursive, since the field is another type of the same name.
+#[derive(Debug, Schematic)]
+struct Item {
+    item: other::Item,
+}
+
+// Every `$ref` must point to a definition in the schema.
+fn assert_refs_resolve(
+    value: &Value,
+    defs: &Value,
+) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(r)) = map.get("$ref") {
+                let name = r.strip_prefix("#/$defs/").unwrap();
+                assert!(defs.get(name).is_some(), "`{}` is not defined", r);
+            }
+            for value in map.values() {
+                assert_refs_resolve(value, defs);
+            }
+        },
+        Value::Array(values) => {
+            for value in values {
+                assert_refs_resolve(value, defs);
+            }
+        },
+        _ => {},
+    }
+}
+
+#[test]
+fn it_tests_self_recursion() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Node>("Node"))?;
+    let defs = &schema["$defs"];
+
+    assert_eq!(defs.as_object().unwrap().len(), 1);
+    let (name, def) = defs.as_object().unwrap().iter().next().unwrap();
+    assert!(name.ends_with("Node"));
+    assert_eq!(def["properties"]["children"]["items"], json!({
+        "$ref": format!("#/$defs/{}", name),
+    }));
+    assert_refs_resolve(&schema, defs);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_recursive_enums() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Expr>("Expr"))?;
+
+    assert_eq!(schema["$ref"], json!("#/$defs/Expr"));
+    assert_eq!(schema["$defs"]["Expr"]["anyOf"][1], json!({
+        "$ref": "#/$defs/Expr",
+    }));
+    assert_eq!(schema["$defs"]["Expr"]["anyOf"][2]["items"][1], json!({
+        "$ref": "#/$defs/Expr",
+    }));
+    assert_refs_resolve(&schema, &schema["$defs"]);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_mutual_recursion() -> rschema::Result<()> {
+    for schema in [
+        Schema::new::<Org>("Org"),
+        Schema::new::<Team>("Team"),
+        Schema::new::<Member>("Member"),
+    ] {
+        let schema = serde_json::to_value(schema)?;
+        let defs = &schema["$defs"];
+
+        assert_eq!(defs.as_object().unwrap().keys().collect::<Vec<_>>(), ["Org"]);
+        assert_refs_resolve(&schema, defs);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn it_validates_recursive_types() {
+    let schema = Schema::new::<Node>("Node");
+
+    assert!(schema.validate(&json!({ "name": "a", "children": [{ "name": "b", "children": [] }] })).is_ok());
+
+    let errors = schema
+        .validate(&json!({ "name": "a", "children": [{ "children": [] }] }))
+        .unwrap_err();
+
+    assert_eq!(errors[0].instance_path, "/children/0");
+}
+
+#[test]
+fn it_tests_mutual_recursion_without_defs() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<A>("A"))?;
+    let defs = &schema["$defs"];
+
+    // Only `A` is referred to, since `B` is written inline in it.
+    assert_eq!(defs.as_object().unwrap().keys().collect::<Vec<_>>(), ["recursion::A"]);
+    assert_eq!(schema["$ref"], json!("#/$defs/recursion::A"));
+    assert_eq!(defs["recursion::A"]["properties"]["b"]["items"]["properties"]["a"]["anyOf"][0], json!({
+        "$ref": "#/$defs/recursion::A",
+    }));
+    assert_refs_resolve(&schema, defs);
+
+    let schema = serde_json::to_value(Schema::new::<B>("B"))?;
+    assert_eq!(schema["$defs"].as_object().unwrap().keys().collect::<Vec<_>>(), ["recursion::B"]);
+    assert_refs_resolve(&schema, &schema["$defs"]);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_same_names_in_other_modules() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Item>("Item"))?;
+
+    assert!(schema.get("$defs").is_none());
+    assert_eq!(schema["properties"]["item"]["properties"]["name"]["type"], "string");
+
+    Ok(())
+}
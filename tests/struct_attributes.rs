@@ -26,7 +26,9 @@ fn it_tests_struct_attributes() -> rschema::Result<()> {
   "type": "object",
   "properties": {
     "prop_required1": {
-      "type": "number"
+      "type": "integer",
+      "minimum": -2147483648,
+      "maximum": 2147483647
     },
     "prop_required2": {
       "type": "string"
@@ -56,25 +56,29 @@ fn it_tests_struct_properties() -> rschema::Result<()> {
     "prop_empty_tuple_struct": {
       "title": "EmptyTupleStruct",
       "type": "array",
-      "items": [],
       "minItems": 0,
       "maxItems": 0
     },
     "prop_new_type_struct": {
       "title": "NewTypeStruct",
-      "type": "number"
+      "type": "integer",
+      "minimum": -2147483648,
+      "maximum": 2147483647
     },
     "prop_tuple_struct": {
       "title": "TupleStruct",
       "type": "array",
       "items": [
         {
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         },
         {
           "type": "string"
         }
       ],
+      "additionalItems": false,
       "minItems": 2,
       "maxItems": 2
     },
@@ -84,7 +88,9 @@ fn it_tests_struct_properties() -> rschema::Result<()> {
       "properties": {
         "value": {
           "title": "i32",
-          "type": "number"
+          "type": "integer",
+          "minimum": -2147483648,
+          "maximum": 2147483647
         }
       },
       "additionalProperties": false
@@ -0,0 +1,70 @@
+use rschema::{
+    Draft,
+    Items,
+    Schema,
+    Schematic,
+    TupleKeys,
+    Type,
+};
+
+#[test]
+fn it_tests_tuple_keys() -> rschema::Result<()> {
+    let ty = Type::Tuple(TupleKeys {
+        items: vec![
+            Type::Boolean,
+            Type::Null,
+        ],
+    });
+    let schema_str = serde_json::to_string_pretty(&ty)?;
+    let schema_str2 = r#"{
+  "type": "array",
+  "items": [
+    {
+      "type": "boolean"
+    },
+    {
+      "type": "null"
+    }
+  ],
+  "additionalItems": false,
+  "minItems": 2,
+  "maxItems": 2
+}"#;
+
+    assert_eq!(schema_str, schema_str2);
+
+    Ok(())
+}
+
+#[derive(Debug, Schematic)]
+struct Empty();
+
+#[test]
+fn it_tests_empty_tuple_keys() -> rschema::Result<()> {
+    let ty = Type::Tuple(TupleKeys { items: vec![] });
+    let schema_str = serde_json::to_string(&ty)?;
+
+    assert_eq!(schema_str, r#"{"type":"array","minItems":0,"maxItems":0}"#);
+
+    Ok(())
+}
+
+#[test]
+fn it_tests_empty_tuple_struct_in_draft202012() -> rschema::Result<()> {
+    let schema_str = Schema::new::<Empty>("Empty")
+        .schema(Draft::Draft202012)
+        .to_string()?;
+
+    assert_eq!(
+        schema_str,
+        r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","title":"Empty","type":"array","minItems":0,"maxItems":0}"#,
+    );
+
+    let schema: Schema = schema_str.parse()?;
+    assert!(matches!(
+        schema.ty(),
+        Type::Array(keys) if matches!(*keys.items, Items::Tuple(ref items) if items.is_empty()),
+    ));
+
+    Ok(())
+}
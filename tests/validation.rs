@@ -0,0 +1,131 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+    ValidationError,
+};
+use serde_json::json;
+
+#[derive(Debug, Schematic)]
+#[rschema(defs = "Item")]
+struct Item {
+    #[rschema(
+        min_length = 1,
+        pattern = r"^\w+$",
+        required,
+    )]
+    name: String,
+
+    #[rschema(
+        exclusive_minimum = 0,
+        multiple_of = 0.5,
+    )]
+    price: f64,
+}
+
+#[derive(Debug, Schematic)]
+enum Kind {
+    Single,
+    Multiple,
+}
+
+#[derive(Debug, Schematic)]
+struct Validated {
+    #[rschema(required)]
+    prop_item: Item,
+
+    #[rschema(
+        max_items = 2,
+        unique_items,
+    )]
+    prop_tags: Vec<String>,
+
+    prop_pair: (u8, bool),
+
+    prop_kind: Kind,
+
+    prop_option: Option<i8>,
+}
+
+fn error(
+    instance_path: &str,
+    schema_path: &str,
+    message: &str,
+) -> ValidationError {
+    ValidationError {
+        instance_path: instance_path.into(),
+        schema_path: schema_path.into(),
+        message: message.into(),
+    }
+}
+
+#[test]
+fn it_tests_valid_instance() {
+    let schema = Schema::new::<Validated>("Validated");
+    let instance = json!({
+        "prop_item": {
+            "name": "apple",
+            "price": 1.5,
+        },
+        "prop_tags": ["red", "fruit"],
+        "prop_pair": [255, true],
+        "prop_kind": "Single",
+        "prop_option": null,
+    });
+
+    assert_eq!(schema.validate(&instance), Ok(()));
+}
+
+#[test]
+fn it_tests_invalid_instance() {
+    let schema = Schema::new::<Validated>("Validated");
+    let instance = json!({
+        "prop_item": {
+            "name": "red apple",
+            "price": 0.75,
+        },
+        "prop_tags": ["red", "fruit", "red"],
+        "prop_pair": [256, true, null],
+        "prop_kind": "Double",
+        "prop_option": -129,
+        "prop_unknown": 1,
+    });
+
+    assert_eq!(
+        schema.validate(&instance),
+        Err(vec![
+            error("/prop_item/name", "/$defs/Item/properties/name/pattern", r"does not match the pattern `^\w+$`"),
+            error("/prop_item/price", "/$defs/Item/properties/price/multipleOf", "is not a multiple of 0.5"),
+            error("/prop_tags", "/properties/prop_tags/maxItems", "has 3 items, but at most 2 are allowed"),
+            error("/prop_tags/2", "/properties/prop_tags/uniqueItems", "is equal to the item at 0"),
+            error("/prop_pair/0", "/properties/prop_pair/items/0/maximum", "is greater than 255"),
+            error("/prop_pair", "/properties/prop_pair/additionalItems", "has 3 items, but at most 2 are allowed"),
+            error("/prop_pair", "/properties/prop_pair/maxItems", "has 3 items, but at most 2 are allowed"),
+            error("/prop_kind", "/properties/prop_kind/enum", r#"is not one of ["Single", "Multiple"]"#),
+            error("/prop_option", "/properties/prop_option/anyOf", "does not match any of the types"),
+            error("/prop_unknown", "/additionalProperties", "is not an allowed property"),
+        ]),
+    );
+}
+
+#[test]
+fn it_tests_type_mismatch() {
+    let schema = Schema::new::<Validated>("Validated");
+    let instance = json!({
+        "prop_item": {
+            "price": "free",
+        },
+        "prop_pair": [1.5, "true"],
+    });
+
+    assert_eq!(
+        schema.validate(&instance),
+        Err(vec![
+            error("/prop_item", "/$defs/Item/required", "does not have the required property `name`"),
+            error("/prop_item/price", "/$defs/Item/properties/price/type", "expected number, found string"),
+            error("/prop_pair/0", "/properties/prop_pair/items/0/type", "expected integer, found number"),
+            error("/prop_pair/1", "/properties/prop_pair/items/1/type", "expected boolean, found string"),
+        ]),
+    );
+}
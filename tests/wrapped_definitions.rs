@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+
+use rschema::{
+    Schema,
+    Schematic,
+};
+use serde_json::Value;
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+macro_rules! defs_struct {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[derive(Debug, Schematic)]
+            #[rschema(defs)]
+            struct $name {
+                value: i32,
+            }
+        )*
+    };
+}
+
+defs_struct!(
+    InOption,
+    InVec,
+    InBox,
+    InMap,
+    InSet,
+    InArray,
+    InSlice,
+    InTupleFirst,
+    InTupleSecond,
+    InRef,
+    InMutRef,
+    InNewType,
+    InVariant,
+);
+
+#[derive(Debug, Schematic)]
+struct NewType(Option<InNewType>);
+
+#[derive(Debug, Schematic)]
+enum Choice {
+    Some(Vec<InVariant>),
+    None,
+}
+
+#[derive(Debug, Schematic)]
+struct Wrappers<'a> {
+    option: Option<InOption>,
+    vec: Vec<InVec>,
+    boxed: Box<InBox>,
+    map: HashMap<String, InMap>,
+    set: HashSet<InSet>,
+    array: [InArray; 2],
+    slice: &'a [InSlice],
+    tuple: (InTupleFirst, Option<InTupleSecond>),
+    reference: &'a InRef,
+    mut_reference: &'a mut InMutRef,
+    newtype: NewType,
+    choice: Choice,
+}
+
+fn refs<'a>(
+    value: &'a Value,
+    found: &mut Vec<&'a str>,
+) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(r)) = map.get("$ref") {
+                found.push(r);
+            }
+            for value in map.values() {
+                refs(value, found);
+            }
+        },
+        Value::Array(values) => {
+            for value in values {
+                refs(value, found);
+            }
+        },
+        _ => {},
+    }
+}
+
+#[test]
+fn it_resolves_every_ref() -> rschema::Result<()> {
+    let schema = serde_json::to_value(Schema::new::<Wrappers>("Wrappers"))?;
+
+    let mut found = vec![];
+    refs(&schema, &mut found);
+
+    assert_eq!(found.len(), 13);
+    for r in found {
+        let name = r.strip_prefix("#/$defs/").unwrap();
+        assert!(schema["$defs"].get(name).is_some(), "`{}` is not defined", r);
+    }
+
+    Ok(())
+}